@@ -0,0 +1,52 @@
+//! Benchmarks comparing iterators with the direct loops. These use the unstable `test`
+//! crate, so they only run with the `nightly` feature:
+//!
+//! ```text
+//! cargo +nightly bench --features nightly
+//! ```
+#![cfg(feature = "nightly")]
+#![feature(test)]
+
+extern crate linked_list;
+extern crate test;
+
+use linked_list::LinkedList;
+use test::{black_box, Bencher};
+
+const LEN: u64 = 100_000;
+
+fn list() -> LinkedList<u64> {
+    (0..LEN).collect()
+}
+
+#[bench]
+fn iter_for_each(b: &mut Bencher) {
+    let list = list();
+    b.iter(|| {
+        let mut sum = 0;
+        black_box(&list).iter().for_each(|&i| sum += i);
+        sum
+    });
+}
+
+#[bench]
+fn for_each_ref(b: &mut Bencher) {
+    let list = list();
+    b.iter(|| {
+        let mut sum = 0;
+        black_box(&list).for_each_ref(|&i| sum += i);
+        sum
+    });
+}
+
+#[bench]
+fn iter_mut_for_each(b: &mut Bencher) {
+    let mut list = list();
+    b.iter(|| black_box(&mut list).iter_mut().for_each(|i| *i += 1));
+}
+
+#[bench]
+fn for_each_mut(b: &mut Bencher) {
+    let mut list = list();
+    b.iter(|| black_box(&mut list).for_each_mut(|i| *i += 1));
+}
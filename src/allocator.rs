@@ -0,0 +1,75 @@
+//! The allocator used for the chunks of nodes.
+//!
+//! With the `allocator_api` feature, the chunks are allocated with an [`Allocator`] from
+//! the standard library. Otherwise the allocator parameter of a list is always the
+//! placeholder [`Global`] defined here, which uses the global allocator.
+//!
+//! [`Allocator`]: https://doc.rust-lang.org/std/alloc/trait.Allocator.html
+//! [`Global`]: struct.Global.html
+
+use alloc::alloc::handle_alloc_error;
+#[cfg(not(feature = "allocator_api"))]
+use alloc::alloc::{alloc, dealloc};
+use core::alloc::Layout;
+#[cfg(feature = "allocator_api")]
+use core::ptr::NonNull;
+
+#[cfg(feature = "allocator_api")]
+pub use alloc::alloc::Global;
+#[cfg(feature = "allocator_api")]
+pub use core::alloc::Allocator;
+
+#[cfg(not(feature = "allocator_api"))]
+pub use self::stable::{Allocator, Global};
+
+#[cfg(not(feature = "allocator_api"))]
+mod stable {
+    /// A placeholder for the unstable `Allocator` trait, which is implemented only by
+    /// [`Global`]. Enable the `allocator_api` feature on nightly to use other
+    /// allocators.
+    ///
+    /// [`Global`]: struct.Global.html
+    pub trait Allocator: private::Sealed {}
+
+    /// The global memory allocator.
+    #[derive(Copy, Clone, Default, Debug)]
+    pub struct Global;
+
+    impl Allocator for Global {}
+
+    mod private {
+        pub trait Sealed {}
+        impl Sealed for super::Global {}
+    }
+}
+
+/// Allocates memory for `layout`, which must have a non-zero size.
+#[cfg(feature = "allocator_api")]
+pub(crate) fn allocate<A: Allocator>(alloc: &A, layout: Layout) -> *mut u8 {
+    match alloc.allocate(layout) {
+        Ok(ptr) => ptr.as_ptr() as *mut u8,
+        Err(_) => handle_alloc_error(layout),
+    }
+}
+
+/// Allocates memory for `layout`, which must have a non-zero size.
+#[cfg(not(feature = "allocator_api"))]
+pub(crate) fn allocate<A: Allocator>(_alloc: &A, layout: Layout) -> *mut u8 {
+    let ptr = unsafe { alloc(layout) };
+    if ptr.is_null() {
+        handle_alloc_error(layout);
+    }
+    ptr
+}
+
+/// Deallocates memory returned by `allocate` with the same allocator and layout.
+#[cfg(feature = "allocator_api")]
+pub(crate) unsafe fn deallocate<A: Allocator>(alloc: &A, ptr: *mut u8, layout: Layout) {
+    alloc.deallocate(NonNull::new_unchecked(ptr), layout);
+}
+
+/// Deallocates memory returned by `allocate` with the same allocator and layout.
+#[cfg(not(feature = "allocator_api"))]
+pub(crate) unsafe fn deallocate<A: Allocator>(_alloc: &A, ptr: *mut u8, layout: Layout) {
+    dealloc(ptr, layout);
+}
@@ -2,9 +2,9 @@
 //! operations.
 use super::*;
 
-use std::fmt;
-use std::iter::Rev;
-use std::marker::PhantomData;
+use core::fmt;
+use core::iter::Rev;
+use core::marker::PhantomData;
 
 /// A cursor with immutable access to the `LinkedList`.
 ///
@@ -20,19 +20,36 @@ use std::marker::PhantomData;
 /// [`cursor_ref_front`]: struct.LinkedList.html#method.cursor_ref_front
 /// [`cursor_ref_back`]: struct.LinkedList.html#method.cursor_ref_back
 pub struct CursorRef<'a, T: 'a> {
-    cursor: *const LinkedNode<T>,
-    index: usize,
+    // The head of the list when the cursor was created, which identifies the list since
+    // lists never share nodes. The list is borrowed by the cursor, so it can't change.
+    pub(crate) list_head: *const LinkedNode<T>,
+    pub(crate) list_len: usize,
+    pub(crate) cursor: *const LinkedNode<T>,
+    pub(crate) index: usize,
     marker: PhantomData<&'a T>,
 }
 
 impl<'a, T> CursorRef<'a, T> {
-    pub(crate) fn create(cursor: *const LinkedNode<T>, index: usize) -> Self {
+    pub(crate) fn create<A: Allocator>(
+        list: &LinkedList<T, A>,
+        cursor: *const LinkedNode<T>,
+        index: usize,
+    ) -> Self {
         CursorRef {
+            list_head: list.head,
+            list_len: list.len,
             cursor,
             index,
             marker: PhantomData,
         }
     }
+    fn with_node(self, cursor: *const LinkedNode<T>, index: usize) -> Self {
+        CursorRef {
+            cursor,
+            index,
+            ..self
+        }
+    }
     /// Returns the next cursor, or `None` if this is the back of the list.
     ///
     /// # Examples
@@ -55,7 +72,7 @@ impl<'a, T> CursorRef<'a, T> {
         if next.is_null() {
             None
         } else {
-            Some(CursorRef::create(next, self.index + 1))
+            Some(self.with_node(next, self.index + 1))
         }
     }
     /// Returns the previous cursor, or `None` if this is the front of the list.
@@ -80,8 +97,62 @@ impl<'a, T> CursorRef<'a, T> {
         if prev.is_null() {
             None
         } else {
-            Some(CursorRef::create(prev, self.index - 1))
+            Some(self.with_node(prev, self.index - 1))
+        }
+    }
+    /// Returns the cursor `n` elements further towards the back, or `None` if the list
+    /// ends before that. Seeking zero steps returns the same cursor.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<u32> = LinkedList::new();
+    /// list.extend(&[1, 2, 3, 4]);
+    ///
+    /// let front = list.cursor_ref_front().unwrap();
+    /// assert!(front.seek_forward(0).unwrap().ptr_eq(front));
+    /// assert_eq!(&3, front.seek_forward(2).unwrap().get());
+    /// assert_eq!(2, front.seek_forward(2).unwrap().index());
+    ///
+    /// // seeking exactly to the back is fine, but not past it
+    /// assert!(front.seek_forward(3).unwrap().is_back());
+    /// assert!(front.seek_forward(4).is_none());
+    /// ```
+    pub fn seek_forward(self, n: usize) -> Option<CursorRef<'a, T>> {
+        let mut cursor = self;
+        for _ in 0..n {
+            cursor = cursor.next()?;
+        }
+        Some(cursor)
+    }
+    /// Returns the cursor `n` elements further towards the front, or `None` if the list
+    /// ends before that. Seeking zero steps returns the same cursor.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<u32> = LinkedList::new();
+    /// list.extend(&[1, 2, 3, 4]);
+    ///
+    /// let back = list.cursor_ref_back().unwrap();
+    /// assert!(back.seek_backward(0).unwrap().ptr_eq(back));
+    /// assert_eq!(&2, back.seek_backward(2).unwrap().get());
+    /// assert_eq!(1, back.seek_backward(2).unwrap().index());
+    ///
+    /// // seeking exactly to the front is fine, but not past it
+    /// assert!(back.seek_backward(3).unwrap().is_front());
+    /// assert!(back.seek_backward(4).is_none());
+    /// ```
+    pub fn seek_backward(self, n: usize) -> Option<CursorRef<'a, T>> {
+        let mut cursor = self;
+        for _ in 0..n {
+            cursor = cursor.prev()?;
         }
+        Some(cursor)
     }
     /// Provides a immutable reference to the element this cursor currently points at. The
     /// reference is bound to the list and can outlive the cursor.
@@ -132,6 +203,87 @@ impl<'a, T> CursorRef<'a, T> {
     pub fn index(self) -> usize {
         self.index
     }
+    /// Returns the length of the list the cursor points into.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<u32> = LinkedList::new();
+    /// list.extend(&[1, 2, 3]);
+    ///
+    /// let cursor = list.cursor_ref_front().unwrap();
+    /// assert_eq!(3, cursor.len());
+    /// ```
+    #[allow(clippy::len_without_is_empty)]
+    pub fn len(self) -> usize {
+        self.list_len
+    }
+    /// Returns the number of elements after the cursor.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<u32> = LinkedList::new();
+    /// list.extend(&[1, 2, 3]);
+    ///
+    /// let cursor = list.cursor_ref_front().unwrap();
+    /// assert_eq!(2, cursor.remaining_forward());
+    /// assert_eq!(0, list.cursor_ref_back().unwrap().remaining_forward());
+    /// ```
+    pub fn remaining_forward(self) -> usize {
+        self.list_len - 1 - self.index
+    }
+    /// Returns the number of elements before the cursor, which is the same as its index.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<u32> = LinkedList::new();
+    /// list.extend(&[1, 2, 3]);
+    ///
+    /// let cursor = list.cursor_ref_back().unwrap();
+    /// assert_eq!(2, cursor.remaining_backward());
+    /// assert_eq!(0, list.cursor_ref_front().unwrap().remaining_backward());
+    /// ```
+    pub fn remaining_backward(self) -> usize {
+        self.index
+    }
+    /// Returns the signed number of steps from this cursor to `other`, which is positive
+    /// if `other` is after this cursor.
+    ///
+    /// Returns `None` if the cursors point into different lists.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<u32> = LinkedList::new();
+    /// list.extend(&[1, 2, 3, 4]);
+    ///
+    /// if let (Some(front), Some(back)) = (list.cursor_ref_front(), list.cursor_ref_back()) {
+    ///     assert_eq!(Some(3), front.distance_to(back));
+    ///     assert_eq!(Some(-3), back.distance_to(front));
+    ///     assert_eq!(Some(0), back.distance_to(back));
+    /// }
+    /// # else { unreachable!(); }
+    ///
+    /// let other_list = list.clone();
+    /// let front = list.cursor_ref_front().unwrap();
+    /// assert_eq!(None, front.distance_to(other_list.cursor_ref_front().unwrap()));
+    /// ```
+    pub fn distance_to(self, other: CursorRef<'a, T>) -> Option<isize> {
+        if !self.same_list(other) {
+            return None;
+        }
+        Some(other.index as isize - self.index as isize)
+    }
     /// Returns `true` if the cursor points to the front of the list.
     ///
     /// # Examples
@@ -198,14 +350,28 @@ impl<'a, T> CursorRef<'a, T> {
     pub fn ptr_eq(self, other: CursorRef<T>) -> bool {
         self.cursor == other.cursor
     }
+    /// Return `true` if the cursors point into the same list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<u32> = LinkedList::new();
+    /// list.extend(&[1, 2, 3]);
+    /// let other_list = list.clone();
+    ///
+    /// let front = list.cursor_ref_front().unwrap();
+    /// assert!(front.same_list(list.cursor_ref_back().unwrap()));
+    /// assert!(!front.same_list(other_list.cursor_ref_front().unwrap()));
+    /// ```
+    pub fn same_list(self, other: CursorRef<T>) -> bool {
+        self.list_head == other.list_head
+    }
 }
 impl<'a, T> Clone for CursorRef<'a, T> {
     fn clone(&self) -> Self {
-        CursorRef {
-            cursor: self.cursor,
-            index: self.index,
-            marker: PhantomData,
-        }
+        *self
     }
 }
 impl<'a, T> Copy for CursorRef<'a, T> {}
@@ -213,7 +379,10 @@ unsafe impl<'a, T: Sync> Send for CursorRef<'a, T> {}
 unsafe impl<'a, T: Sync> Sync for CursorRef<'a, T> {}
 impl<'a, T: fmt::Debug> fmt::Debug for CursorRef<'a, T> {
     fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
-        f.debug_tuple("CursorRef").field(self.get()).finish()
+        f.debug_struct("CursorRef")
+            .field("index", &self.index)
+            .field("value", self.get())
+            .finish()
     }
 }
 
@@ -227,15 +396,15 @@ impl<'a, T: fmt::Debug> fmt::Debug for CursorRef<'a, T> {
 /// [`get`]: #method.get
 /// [`cursor_mut_front`]: struct.LinkedList.html#method.cursor_mut_front
 /// [`cursor_mut_back`]: struct.LinkedList.html#method.cursor_mut_back
-pub struct CursorMut<'a, T: 'a> {
-    list: &'a mut LinkedList<T>,
+pub struct CursorMut<'a, T: 'a, A: Allocator + 'a = Global> {
+    list: &'a mut LinkedList<T, A>,
     cursor: *mut LinkedNode<T>,
     index: usize,
 }
 
-impl<'a, T> CursorMut<'a, T> {
+impl<'a, T, A: Allocator> CursorMut<'a, T, A> {
     pub(crate) fn create(
-        list: &'a mut LinkedList<T>,
+        list: &'a mut LinkedList<T, A>,
         cursor: *mut LinkedNode<T>,
         index: usize,
     ) -> Self {
@@ -300,7 +469,7 @@ impl<'a, T> CursorMut<'a, T> {
     ///     assert!(back.next().is_none());
     /// }
     /// ```
-    pub fn next(mut self) -> Option<CursorMut<'a, T>> {
+    pub fn next(mut self) -> Option<CursorMut<'a, T, A>> {
         if self.go_next() {
             Some(self)
         } else {
@@ -362,13 +531,57 @@ impl<'a, T> CursorMut<'a, T> {
     ///     assert!(back.prev().is_some());
     /// }
     /// ```
-    pub fn prev(mut self) -> Option<CursorMut<'a, T>> {
+    pub fn prev(mut self) -> Option<CursorMut<'a, T, A>> {
         if self.go_prev() {
             Some(self)
         } else {
             None
         }
     }
+    /// Move the cursor to the element at the given index, walking from the current
+    /// position in whichever direction is needed. Returns `false` and leaves the cursor
+    /// unchanged if `index` is out of bounds.
+    ///
+    /// This is `O(|index - self.index()|)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<u32> = LinkedList::new();
+    /// list.extend(&[10, 11, 12, 13, 14]);
+    ///
+    /// if let Some(mut cursor) = list.cursor_mut_front() {
+    ///     assert!(cursor.move_to(3));
+    ///     assert_eq!(3, cursor.index());
+    ///     assert_eq!(&13, cursor.get_ref());
+    ///
+    ///     assert!(cursor.move_to(1));
+    ///     assert_eq!(&11, cursor.get_ref());
+    ///
+    ///     // moving to the current index does nothing
+    ///     assert!(cursor.move_to(1));
+    ///     assert_eq!(&11, cursor.get_ref());
+    ///
+    ///     // the list only has five elements
+    ///     assert!(!cursor.move_to(5));
+    ///     assert_eq!(1, cursor.index());
+    /// }
+    /// # else { unreachable!(); }
+    /// ```
+    pub fn move_to(&mut self, index: usize) -> bool {
+        if index >= self.list.len {
+            return false;
+        }
+        while self.index < index {
+            self.go_next();
+        }
+        while self.index > index {
+            self.go_prev();
+        }
+        true
+    }
 
     /// Insert a new node into the linked list. This method does not move the cursor, and
     /// the newly created element will be the next element when it returns.
@@ -407,6 +620,52 @@ impl<'a, T> CursorMut<'a, T> {
             }
         }
     }
+    /// Insert the elements of the iterator after the cursor, in the order they are
+    /// yielded. This method does not move the cursor, so the first element of the
+    /// iterator will be the next element when it returns.
+    ///
+    /// Capacity is reserved up front using the size hint of the iterator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<u32> = LinkedList::new();
+    /// list.extend(&[1, 5]);
+    ///
+    /// if let Some(mut front) = list.cursor_mut_front() {
+    ///     front.insert_many(vec![2, 3, 4]);
+    ///     assert_eq!(&1, front.get_ref());
+    ///     assert_eq!(0, front.index());
+    ///     assert_eq!(Some(&2), front.peek_next());
+    /// }
+    /// # else { unreachable!(); }
+    /// assert_eq!(list, vec![1, 2, 3, 4, 5]);
+    /// ```
+    pub fn insert_many<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let iter = iter.into_iter();
+        let additional = self.list.reserve_hint(iter.size_hint());
+        self.list.reserve(additional);
+
+        let mut last = self.cursor;
+        for value in iter {
+            // the list is kept valid after every insertion, in case the iterator panics
+            let next = unsafe { (*last).next };
+            let node = self.list.new_node(next, last, value);
+            self.list.len += 1;
+
+            unsafe {
+                (*last).next = node;
+                if next.is_null() {
+                    self.list.tail = node;
+                } else {
+                    (*next).prev = node;
+                }
+            }
+            last = node;
+        }
+    }
     /// Insert a new node into the linked list. This method does not move the cursor, and
     /// the newly created element will be the previous element when it returns.
     ///
@@ -445,8 +704,8 @@ impl<'a, T> CursorMut<'a, T> {
             }
         }
     }
-
-    /// Remove the value and consume the cursor.
+    /// Insert a new node after the current element and move the cursor to it. Returns
+    /// the index of the new element.
     ///
     /// # Examples
     ///
@@ -454,44 +713,25 @@ impl<'a, T> CursorMut<'a, T> {
     /// use linked_list::LinkedList;
     ///
     /// let mut list: LinkedList<u32> = LinkedList::new();
-    /// for i in 0..8 {
-    ///     list.push_back(i*i);
-    /// }
+    /// list.push_back(1);
+    /// list.push_back(4);
     ///
-    /// // let's remove the value at index 4
-    /// if let Some(mut cursor) = list.cursor_mut_front() {
-    ///     while cursor.index() != 4 {
-    ///         assert!(cursor.go_next());
-    ///     }
-    ///     assert_eq!(cursor.remove(), 16);
+    /// if let Some(mut front) = list.cursor_mut_front() {
+    ///     assert_eq!(1, front.insert_next_and_move(2));
+    ///     assert_eq!(&2, front.get_ref());
+    ///     assert_eq!(2, front.insert_next_and_move(3));
+    ///     assert_eq!(&3, front.get_ref());
     /// }
-    /// assert_eq!(list, vec![0, 1, 4, 9, 25, 36, 49]);
+    ///# else { unreachable!(); }
+    /// assert_eq!(list, vec![1, 2, 3, 4]);
     /// ```
-    pub fn remove(self) -> T {
-        unsafe {
-            let prev = (*self.cursor).prev;
-            let next = (*self.cursor).next;
-
-            if prev.is_null() {
-                self.list.head = next;
-            } else {
-                (*prev).next = next;
-            }
-
-            if next.is_null() {
-                self.list.tail = prev;
-            } else {
-                (*next).prev = prev;
-            }
-
-            let value = ptr::read(&(*self.cursor).value);
-            self.list.discard_node(self.cursor);
-            self.list.len -= 1;
-            value
-        }
+    pub fn insert_next_and_move(&mut self, value: T) -> usize {
+        self.insert_next(value);
+        self.go_next();
+        self.index
     }
-    /// Remove the value and return the cursor to the next element, or `None` if this is
-    /// the back.
+    /// Insert a new node before the current element and move the cursor to it. Returns
+    /// the index of the new element.
     ///
     /// # Examples
     ///
@@ -499,58 +739,86 @@ impl<'a, T> CursorMut<'a, T> {
     /// use linked_list::LinkedList;
     ///
     /// let mut list: LinkedList<u32> = LinkedList::new();
-    /// for i in 0..8 {
-    ///     list.push_back(i*i);
-    /// }
+    /// list.push_back(1);
+    /// list.push_back(4);
     ///
-    /// // let's remove the value at index 4
-    /// if let Some(mut cursor) = list.cursor_mut_front() {
-    ///     while cursor.index() != 4 {
-    ///         assert!(cursor.go_next());
-    ///     }
-    ///#    assert_eq!(4, cursor.index());
-    ///     if let (removed, Some(next)) = cursor.remove_go_next() {
-    ///#        assert_eq!(4, next.index());
-    ///         assert_eq!(16, removed);
-    ///         assert_eq!(&25, next.get_ref());
-    ///         assert_eq!(&36, next.next().unwrap().get_ref());
-    ///     }
-    ///#    else { unreachable!(); }
+    /// if let Some(mut back) = list.cursor_mut_back() {
+    ///     assert_eq!(1, back.insert_prev_and_move(3));
+    ///     assert_eq!(&3, back.get_ref());
+    ///     assert_eq!(1, back.insert_prev_and_move(2));
+    ///     assert_eq!(&2, back.get_ref());
     /// }
     ///# else { unreachable!(); }
+    /// assert_eq!(list, vec![1, 2, 3, 4]);
+    /// ```
+    pub fn insert_prev_and_move(&mut self, value: T) -> usize {
+        self.insert_prev(value);
+        self.go_prev();
+        self.index
+    }
+}
+
+impl<'a, T> CursorMut<'a, T> {
+    /// Move every element of `other` to just after the cursor, leaving `other` empty.
+    /// This method does not move the cursor, so the first element of `other` will be the
+    /// next element when it returns.
+    ///
+    /// Like [`append`], this moves the nodes and allocations of `other` into this list,
+    /// see [node ownership].
+    ///
+    /// [`append`]: struct.LinkedList.html#method.append
+    /// [node ownership]: index.html#node-ownership
+    ///
+    /// # Examples
     ///
-    /// assert_eq!(list, vec![0, 1, 4, 9, 25, 36, 49]);
     /// ```
-    pub fn remove_go_next(self) -> (T, Option<CursorMut<'a, T>>) {
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<u32> = LinkedList::new();
+    /// list.extend(&[1, 5]);
+    /// let mut other: LinkedList<u32> = LinkedList::new();
+    /// other.extend(&[2, 3, 4]);
+    ///
+    /// if let Some(mut front) = list.cursor_mut_front() {
+    ///     front.splice_after_list(&mut other);
+    ///     assert_eq!(Some(&2), front.peek_next());
+    /// }
+    /// # else { unreachable!(); }
+    /// assert_eq!(list, vec![1, 2, 3, 4, 5]);
+    /// assert!(other.is_empty());
+    /// assert_eq!(0, other.capacity());
+    /// ```
+    pub fn splice_after_list(&mut self, other: &mut LinkedList<T>) {
+        // the global allocator can free memory allocated for any list
+        unsafe { self.list.take_memory(other) };
+        if other.is_empty() {
+            return;
+        }
         unsafe {
-            let cursor = self.cursor;
-            let prev = (*cursor).prev;
-            let next = (*cursor).next;
-
-            if prev.is_null() {
-                self.list.head = next;
-            } else {
-                (*prev).next = next;
-            }
-
-            if next.is_null() {
-                self.list.tail = prev;
-            } else {
-                (*next).prev = prev;
-            }
-
-            let value = ptr::read(&(*cursor).value);
-            self.list.discard_node(cursor);
-            self.list.len -= 1;
+            let next = (*self.cursor).next;
+            (*self.cursor).next = other.head;
+            (*other.head).prev = self.cursor;
+            (*other.tail).next = next;
             if next.is_null() {
-                (value, None)
+                self.list.tail = other.tail;
             } else {
-                (value, Some(CursorMut::create(self.list, next, self.index)))
+                (*next).prev = other.tail;
             }
         }
+        self.list.len += other.len;
+        other.head = ptr::null_mut();
+        other.tail = ptr::null_mut();
+        other.len = 0;
     }
-    /// Remove the value and return the cursor to the previous element, or `None` if this
-    /// is the front.
+    /// Move every element of `other` to just before the cursor, leaving `other` empty.
+    /// This method does not move the cursor, so the last element of `other` will be the
+    /// previous element when it returns.
+    ///
+    /// Like [`append`], this moves the nodes and allocations of `other` into this list,
+    /// see [node ownership].
+    ///
+    /// [`append`]: struct.LinkedList.html#method.append
+    /// [node ownership]: index.html#node-ownership
     ///
     /// # Examples
     ///
@@ -558,19 +826,426 @@ impl<'a, T> CursorMut<'a, T> {
     /// use linked_list::LinkedList;
     ///
     /// let mut list: LinkedList<u32> = LinkedList::new();
-    /// for i in 0..8 {
-    ///     list.push_back(i*i);
+    /// list.extend(&[1, 5]);
+    /// let mut other: LinkedList<u32> = LinkedList::new();
+    /// other.extend(&[2, 3, 4]);
+    ///
+    /// if let Some(mut back) = list.cursor_mut_back() {
+    ///     back.splice_before_list(&mut other);
+    ///     assert_eq!(4, back.index());
+    ///     assert_eq!(Some(&4), back.peek_prev());
     /// }
-    ///
-    /// // let's remove the value at index 4
-    /// if let Some(mut cursor) = list.cursor_mut_front() {
-    ///     while cursor.index() != 4 {
-    ///         assert!(cursor.go_next());
-    ///     }
-    ///#    assert_eq!(4, cursor.index());
-    ///     if let (removed, Some(next)) = cursor.remove_go_prev() {
-    ///#        assert_eq!(3, next.index());
-    ///         assert_eq!(16, removed);
+    /// # else { unreachable!(); }
+    /// assert_eq!(list, vec![1, 2, 3, 4, 5]);
+    /// assert!(other.is_empty());
+    /// assert_eq!(0, other.capacity());
+    /// ```
+    pub fn splice_before_list(&mut self, other: &mut LinkedList<T>) {
+        // the global allocator can free memory allocated for any list
+        unsafe { self.list.take_memory(other) };
+        if other.is_empty() {
+            return;
+        }
+        unsafe {
+            let prev = (*self.cursor).prev;
+            (*self.cursor).prev = other.tail;
+            (*other.tail).next = self.cursor;
+            (*other.head).prev = prev;
+            if prev.is_null() {
+                self.list.head = other.head;
+            } else {
+                (*prev).next = other.head;
+            }
+        }
+        self.index += other.len;
+        self.list.len += other.len;
+        other.head = ptr::null_mut();
+        other.tail = ptr::null_mut();
+        other.len = 0;
+    }
+}
+
+impl<'a, T, A: Allocator> CursorMut<'a, T, A> {
+    /// Swap the current element with the next element by relinking the two nodes,
+    /// leaving the cursor pointing at the same value. Returns `false` if the cursor is at
+    /// the back of the list, in which case nothing happens.
+    ///
+    /// The values are not moved, so this is `O(1)` regardless of the size of `T`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<u32> = LinkedList::new();
+    /// list.extend(&[1, 2, 3]);
+    ///
+    /// if let Some(mut cursor) = list.cursor_mut_front() {
+    ///     assert!(cursor.swap_next());
+    ///     // the cursor follows the value
+    ///     assert_eq!(1, cursor.index());
+    ///     assert_eq!(&1, cursor.get_ref());
+    ///
+    ///     assert!(cursor.swap_next());
+    ///     assert!(cursor.is_back());
+    ///     assert!(!cursor.swap_next());
+    /// }
+    /// # else { unreachable!(); }
+    ///
+    /// assert_eq!(list, vec![2, 3, 1]);
+    /// ```
+    pub fn swap_next(&mut self) -> bool {
+        let next = unsafe { (*self.cursor).next };
+        if next.is_null() {
+            false
+        } else {
+            unsafe {
+                self.list.swap_adjacent(self.cursor, next);
+            }
+            self.index += 1;
+            true
+        }
+    }
+    /// Move the current element to just after the element at index `target` by relinking
+    /// its node, leaving the cursor pointing at the same value. The values are not moved,
+    /// so this is `O(1)` apart from finding the target node.
+    ///
+    /// The target is given as an index rather than a [`CursorRef`], since a `CursorRef`
+    /// can't be held while this cursor mutates the list. Moving the element to after
+    /// itself or to after its current predecessor does nothing.
+    ///
+    /// [`CursorRef`]: struct.CursorRef.html
+    ///
+    /// # Panics
+    ///
+    /// Panics if `target` is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<u32> = LinkedList::new();
+    /// list.extend(&[1, 2, 3, 4, 5]);
+    ///
+    /// if let Some(mut cursor) = list.cursor_mut_at(1) {
+    ///     cursor.move_node_after(4);
+    ///     // the cursor follows the value
+    ///     assert_eq!(4, cursor.index());
+    ///     assert_eq!(&2, cursor.get_ref());
+    ///
+    ///     cursor.move_node_after(0);
+    ///     assert_eq!(1, cursor.index());
+    /// }
+    /// # else { unreachable!(); }
+    ///
+    /// assert_eq!(list, vec![1, 2, 3, 4, 5]);
+    /// ```
+    pub fn move_node_after(&mut self, target: usize) {
+        assert!(
+            target < self.list.len,
+            "target (is {}) should be < len (is {})",
+            target,
+            self.list.len
+        );
+        if target == self.index || target + 1 == self.index {
+            return;
+        }
+        let target_node = self.list.node_at(target);
+        let node = self.cursor;
+        unsafe {
+            // unlink the node
+            let prev = (*node).prev;
+            let next = (*node).next;
+            if prev.is_null() {
+                self.list.head = next;
+            } else {
+                (*prev).next = next;
+            }
+            if next.is_null() {
+                self.list.tail = prev;
+            } else {
+                (*next).prev = prev;
+            }
+
+            // and link it in after the target, which is not the node itself
+            let after = (*target_node).next;
+            (*node).prev = target_node;
+            (*node).next = after;
+            (*target_node).next = node;
+            if after.is_null() {
+                self.list.tail = node;
+            } else {
+                (*after).prev = node;
+            }
+        }
+        // the elements between the old and new position shift by one
+        self.index = if target > self.index {
+            target
+        } else {
+            target + 1
+        };
+    }
+    /// Rotate the list so the current element becomes the front, moving the elements
+    /// before it to the back of the list in the same order. The nodes are relinked, so
+    /// this is `O(1)`.
+    ///
+    /// This consumes the cursor, since the indexes of every element change.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<u32> = LinkedList::new();
+    /// list.extend(&[1, 2, 3, 4, 5]);
+    ///
+    /// list.cursor_mut_at(3).unwrap().rotate_to_front();
+    /// assert_eq!(list, vec![4, 5, 1, 2, 3]);
+    /// ```
+    pub fn rotate_to_front(self) {
+        let list = self.list;
+        let node = self.cursor;
+        unsafe {
+            let prev = (*node).prev;
+            if prev.is_null() {
+                // already the front
+                return;
+            }
+            (*list.tail).next = list.head;
+            (*list.head).prev = list.tail;
+            (*prev).next = ptr::null_mut();
+            (*node).prev = ptr::null_mut();
+            list.head = node;
+            list.tail = prev;
+        }
+    }
+
+    /// Remove the value and consume the cursor.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<u32> = LinkedList::new();
+    /// for i in 0..8 {
+    ///     list.push_back(i*i);
+    /// }
+    ///
+    /// // let's remove the value at index 4
+    /// if let Some(mut cursor) = list.cursor_mut_front() {
+    ///     while cursor.index() != 4 {
+    ///         assert!(cursor.go_next());
+    ///     }
+    ///     assert_eq!(cursor.remove(), 16);
+    /// }
+    /// assert_eq!(list, vec![0, 1, 4, 9, 25, 36, 49]);
+    /// ```
+    pub fn remove(self) -> T {
+        unsafe {
+            let prev = (*self.cursor).prev;
+            let next = (*self.cursor).next;
+
+            if prev.is_null() {
+                self.list.head = next;
+            } else {
+                (*prev).next = next;
+            }
+
+            if next.is_null() {
+                self.list.tail = prev;
+            } else {
+                (*next).prev = prev;
+            }
+
+            let value = ptr::read(&(*self.cursor).value);
+            self.list.discard_node(self.cursor);
+            self.list.len -= 1;
+            value
+        }
+    }
+    /// Remove the value and return the cursor to the next element, or `None` if this is
+    /// the back.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<u32> = LinkedList::new();
+    /// for i in 0..8 {
+    ///     list.push_back(i*i);
+    /// }
+    ///
+    /// // let's remove the value at index 4
+    /// if let Some(mut cursor) = list.cursor_mut_front() {
+    ///     while cursor.index() != 4 {
+    ///         assert!(cursor.go_next());
+    ///     }
+    ///#    assert_eq!(4, cursor.index());
+    ///     if let (removed, Some(next)) = cursor.remove_go_next() {
+    ///#        assert_eq!(4, next.index());
+    ///         assert_eq!(16, removed);
+    ///         assert_eq!(&25, next.get_ref());
+    ///         assert_eq!(&36, next.next().unwrap().get_ref());
+    ///     }
+    ///#    else { unreachable!(); }
+    /// }
+    ///# else { unreachable!(); }
+    ///
+    /// assert_eq!(list, vec![0, 1, 4, 9, 25, 36, 49]);
+    /// ```
+    pub fn remove_go_next(self) -> (T, Option<CursorMut<'a, T, A>>) {
+        unsafe {
+            let cursor = self.cursor;
+            let prev = (*cursor).prev;
+            let next = (*cursor).next;
+
+            if prev.is_null() {
+                self.list.head = next;
+            } else {
+                (*prev).next = next;
+            }
+
+            if next.is_null() {
+                self.list.tail = prev;
+            } else {
+                (*next).prev = prev;
+            }
+
+            let value = ptr::read(&(*cursor).value);
+            self.list.discard_node(cursor);
+            self.list.len -= 1;
+            if next.is_null() {
+                (value, None)
+            } else {
+                (value, Some(CursorMut::create(self.list, next, self.index)))
+            }
+        }
+    }
+    /// Remove `count` elements starting at the cursor, or every element from the cursor
+    /// to the back if there are fewer. Returns the removed elements in a new list, and
+    /// the cursor to the element after them, or `None` if they reached the back.
+    ///
+    /// The values are moved into a new list with room for exactly the removed elements
+    /// and the same chunk size, and their nodes are kept as unused capacity in this list.
+    /// If `count` is zero, nothing is removed and the returned cursor is this cursor.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<u32> = LinkedList::new();
+    /// list.extend(&[1, 2, 3, 4, 5]);
+    ///
+    /// if let Some(cursor) = list.cursor_mut_at(1) {
+    ///     let (removed, cursor) = cursor.remove_range(2);
+    ///     assert_eq!(removed, vec![2, 3]);
+    ///     let cursor = cursor.unwrap();
+    ///     assert_eq!(&4, cursor.get_ref());
+    ///     assert_eq!(1, cursor.index());
+    /// }
+    /// # else { unreachable!(); }
+    /// assert_eq!(list, vec![1, 4, 5]);
+    /// ```
+    pub fn remove_range(self, count: usize) -> (LinkedList<T, A>, Option<CursorMut<'a, T, A>>)
+    where
+        A: Clone,
+    {
+        let count = cmp::min(count, self.list.len - self.index);
+        let mut removed = LinkedList::with_capacity_and_chunk_size_in(
+            count,
+            self.list.chunk_size,
+            self.list.alloc.clone(),
+        );
+        let mut cursor = Some(self);
+        for _ in 0..count {
+            // count is clamped, so we never run past the back
+            let (value, next) = cursor.unwrap().remove_go_next();
+            removed.push_back(value);
+            cursor = next;
+        }
+        (removed, cursor)
+    }
+    /// Remove consecutive elements starting at the cursor for as long as `f` returns
+    /// `true`. Returns the number of removed elements, and the cursor to the first
+    /// element for which `f` returned `false`, or `None` if every element from the cursor
+    /// to the back was removed.
+    ///
+    /// This consumes the cursor, since it can't point to a removed element. If no
+    /// elements are removed, the returned cursor is this cursor.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<char> = LinkedList::new();
+    /// list.extend("ab   cd".chars());
+    ///
+    /// if let Some(cursor) = list.cursor_mut_at(2) {
+    ///     let (removed, cursor) = cursor.delete_while(|c| c.is_whitespace());
+    ///     assert_eq!(3, removed);
+    ///     let cursor = cursor.unwrap();
+    ///     assert_eq!(&'c', cursor.get_ref());
+    ///     assert_eq!(2, cursor.index());
+    /// }
+    /// # else { unreachable!(); }
+    /// assert_eq!(list, vec!['a', 'b', 'c', 'd']);
+    ///
+    /// // removing up to the back returns no cursor
+    /// if let Some(cursor) = list.cursor_mut_at(2) {
+    ///     let (removed, cursor) = cursor.delete_while(|c| c.is_alphabetic());
+    ///     assert_eq!(2, removed);
+    ///     assert!(cursor.is_none());
+    /// }
+    /// # else { unreachable!(); }
+    /// assert_eq!(list, vec!['a', 'b']);
+    /// ```
+    pub fn delete_while(
+        self,
+        mut f: impl FnMut(&T) -> bool,
+    ) -> (usize, Option<CursorMut<'a, T, A>>) {
+        let mut removed = 0;
+        let mut cursor = self;
+        loop {
+            if !f(cursor.get_ref()) {
+                return (removed, Some(cursor));
+            }
+            let (value, next) = cursor.remove_go_next();
+            removed += 1;
+            // the list is valid before the value is dropped, in case drop panics
+            drop(value);
+            match next {
+                Some(next) => cursor = next,
+                None => return (removed, None),
+            }
+        }
+    }
+    /// Remove the value and return the cursor to the previous element, or `None` if this
+    /// is the front.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<u32> = LinkedList::new();
+    /// for i in 0..8 {
+    ///     list.push_back(i*i);
+    /// }
+    ///
+    /// // let's remove the value at index 4
+    /// if let Some(mut cursor) = list.cursor_mut_front() {
+    ///     while cursor.index() != 4 {
+    ///         assert!(cursor.go_next());
+    ///     }
+    ///#    assert_eq!(4, cursor.index());
+    ///     if let (removed, Some(next)) = cursor.remove_go_prev() {
+    ///#        assert_eq!(3, next.index());
+    ///         assert_eq!(16, removed);
     ///         assert_eq!(&9, next.get_ref());
     ///         assert_eq!(&25, next.next().unwrap().get_ref());
     ///     }
@@ -580,7 +1255,7 @@ impl<'a, T> CursorMut<'a, T> {
     ///
     /// assert_eq!(list, vec![0, 1, 4, 9, 25, 36, 49]);
     /// ```
-    pub fn remove_go_prev(self) -> (T, Option<CursorMut<'a, T>>) {
+    pub fn remove_go_prev(self) -> (T, Option<CursorMut<'a, T, A>>) {
         unsafe {
             let cursor = self.cursor;
             let prev = (*cursor).prev;
@@ -601,7 +1276,7 @@ impl<'a, T> CursorMut<'a, T> {
             let value = ptr::read(&(*cursor).value);
             self.list.discard_node(cursor);
             self.list.len -= 1;
-            if next.is_null() {
+            if prev.is_null() {
                 (value, None)
             } else {
                 (
@@ -612,6 +1287,55 @@ impl<'a, T> CursorMut<'a, T> {
         }
     }
 
+    /// Remove the value and insert the elements of the iterator in its place, preserving
+    /// the order of the iterator. This consumes the cursor and returns the removed value.
+    ///
+    /// If the iterator is empty, this is equivalent to [`remove`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<u32> = LinkedList::new();
+    /// list.extend(&[1, 2, 3]);
+    ///
+    /// if let Some(cursor) = list.cursor_mut_at(1) {
+    ///     assert_eq!(2, cursor.replace_with(vec![20, 21, 22]));
+    /// }
+    /// # else { unreachable!(); }
+    /// assert_eq!(list, vec![1, 20, 21, 22, 3]);
+    ///
+    /// // replacing with nothing removes the element
+    /// if let Some(cursor) = list.cursor_mut_back() {
+    ///     assert_eq!(3, cursor.replace_with(None));
+    /// }
+    /// # else { unreachable!(); }
+    /// assert_eq!(list, vec![1, 20, 21, 22]);
+    ///
+    /// // replacing the only element of a list
+    /// let mut single: LinkedList<u32> = LinkedList::new();
+    /// single.push_back(7);
+    /// if let Some(cursor) = single.cursor_mut_front() {
+    ///     assert_eq!(7, cursor.replace_with(vec![8, 9]));
+    /// }
+    /// # else { unreachable!(); }
+    /// assert_eq!(single, vec![8, 9]);
+    /// assert_eq!(Some(&8), single.front());
+    /// assert_eq!(Some(&9), single.back());
+    /// ```
+    ///
+    /// [`remove`]: #method.remove
+    pub fn replace_with<I: IntoIterator<Item = T>>(mut self, iter: I) -> T {
+        let iter = iter.into_iter();
+        self.list.reserve(iter.size_hint().0);
+        for value in iter {
+            // inserting right before the cursor keeps the order of the iterator
+            self.insert_prev(value);
+        }
+        self.remove()
+    }
+
     /// Swap the current value for a new value.
     ///
     /// # Examples
@@ -644,6 +1368,57 @@ impl<'a, T> CursorMut<'a, T> {
         }
     }
 
+    /// Replace the current value with a new value, returning the old value. This is the
+    /// same as [`swap`].
+    ///
+    /// [`swap`]: #method.swap
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<String> = LinkedList::new();
+    /// list.push_back("a".to_string());
+    /// list.push_back("b".to_string());
+    ///
+    /// if let Some(mut cursor) = list.cursor_mut_back() {
+    ///     assert_eq!(cursor.replace("c".to_string()), "b");
+    /// }
+    ///# else { unreachable!(); }
+    ///
+    /// assert_eq!(list, vec!["a", "c"]);
+    /// ```
+    pub fn replace(&mut self, value: T) -> T {
+        self.swap(value)
+    }
+
+    /// Take the current value, leaving `T::default()` in its place.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<Vec<u32>> = LinkedList::new();
+    /// list.push_back(vec![1, 2]);
+    /// list.push_back(vec![3]);
+    ///
+    /// if let Some(mut cursor) = list.cursor_mut_front() {
+    ///     assert_eq!(cursor.take(), [1, 2]);
+    ///     assert!(cursor.get_ref().is_empty());
+    /// }
+    ///# else { unreachable!(); }
+    ///
+    /// assert_eq!(list, vec![vec![], vec![3]]);
+    /// ```
+    pub fn take(&mut self) -> T
+    where
+        T: Default,
+    {
+        self.swap(T::default())
+    }
+
     /// Provides a mutable reference to the element this cursor currently points at.
     ///
     /// # Safety
@@ -674,8 +1449,7 @@ impl<'a, T> CursorMut<'a, T> {
     /// ```
     ///
     /// [`append`]: struct.LinkedList.html#method.append
-    #[allow(unknown_lints)]
-    #[allow(needless_lifetimes)]
+    #[allow(clippy::needless_lifetimes)]
     pub fn get<'cursor>(&'cursor mut self) -> &'cursor mut T {
         unsafe { &mut (*self.cursor).value }
     }
@@ -696,8 +1470,7 @@ impl<'a, T> CursorMut<'a, T> {
     /// }
     /// # else { unreachable!(); }
     /// ```
-    #[allow(unknown_lints)]
-    #[allow(needless_lifetimes)]
+    #[allow(clippy::needless_lifetimes)]
     pub fn get_ref<'cursor>(&'cursor self) -> &'cursor T {
         unsafe { &(*self.cursor).value }
     }
@@ -723,6 +1496,141 @@ impl<'a, T> CursorMut<'a, T> {
     pub fn into_mut(self) -> &'a mut T {
         unsafe { &mut (*self.cursor).value }
     }
+    /// Provides an immutable reference to the next element without moving the cursor,
+    /// or `None` if the cursor is at the back of the list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<u32> = LinkedList::new();
+    /// list.push_back(1);
+    /// list.push_back(2);
+    /// list.push_back(3);
+    ///
+    /// if let Some(mut cursor) = list.cursor_mut_front() {
+    ///     assert_eq!(Some(&2), cursor.peek_next());
+    ///     assert!(cursor.go_next());
+    ///     assert_eq!(Some(&3), cursor.peek_next());
+    ///     assert!(cursor.go_next());
+    ///     assert_eq!(None, cursor.peek_next());
+    /// }
+    /// # else { unreachable!(); }
+    /// ```
+    #[allow(clippy::needless_lifetimes)]
+    pub fn peek_next<'cursor>(&'cursor self) -> Option<&'cursor T> {
+        unsafe {
+            let next = (*self.cursor).next;
+            if next.is_null() {
+                None
+            } else {
+                Some(&(*next).value)
+            }
+        }
+    }
+    /// Provides an immutable reference to the previous element without moving the
+    /// cursor, or `None` if the cursor is at the front of the list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<u32> = LinkedList::new();
+    /// list.push_back(1);
+    /// list.push_back(2);
+    /// list.push_back(3);
+    ///
+    /// if let Some(mut cursor) = list.cursor_mut_back() {
+    ///     assert_eq!(Some(&2), cursor.peek_prev());
+    ///     assert!(cursor.go_prev());
+    ///     // we can peek in both directions at the same time
+    ///     assert_eq!((Some(&1), Some(&3)), (cursor.peek_prev(), cursor.peek_next()));
+    ///     assert!(cursor.go_prev());
+    ///     assert_eq!(None, cursor.peek_prev());
+    /// }
+    /// # else { unreachable!(); }
+    /// ```
+    #[allow(clippy::needless_lifetimes)]
+    pub fn peek_prev<'cursor>(&'cursor self) -> Option<&'cursor T> {
+        unsafe {
+            let prev = (*self.cursor).prev;
+            if prev.is_null() {
+                None
+            } else {
+                Some(&(*prev).value)
+            }
+        }
+    }
+    /// Provides a mutable reference to the next element without moving the cursor, or
+    /// `None` if the cursor is at the back of the list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<u32> = LinkedList::new();
+    /// list.push_back(1);
+    /// list.push_back(2);
+    ///
+    /// if let Some(mut cursor) = list.cursor_mut_front() {
+    ///     if let Some(next) = cursor.peek_next_mut() {
+    ///         *next = 5;
+    ///     }
+    ///     assert!(cursor.go_next());
+    ///     assert!(cursor.peek_next_mut().is_none());
+    /// }
+    /// # else { unreachable!(); }
+    ///
+    /// assert_eq!(list, vec![1, 5]);
+    /// ```
+    #[allow(clippy::needless_lifetimes)]
+    pub fn peek_next_mut<'cursor>(&'cursor mut self) -> Option<&'cursor mut T> {
+        unsafe {
+            let next = (*self.cursor).next;
+            if next.is_null() {
+                None
+            } else {
+                Some(&mut (*next).value)
+            }
+        }
+    }
+    /// Provides a mutable reference to the previous element without moving the cursor,
+    /// or `None` if the cursor is at the front of the list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<u32> = LinkedList::new();
+    /// list.push_back(1);
+    /// list.push_back(2);
+    ///
+    /// if let Some(mut cursor) = list.cursor_mut_back() {
+    ///     if let Some(prev) = cursor.peek_prev_mut() {
+    ///         *prev = 5;
+    ///     }
+    ///     assert!(cursor.go_prev());
+    ///     assert!(cursor.peek_prev_mut().is_none());
+    /// }
+    /// # else { unreachable!(); }
+    ///
+    /// assert_eq!(list, vec![5, 2]);
+    /// ```
+    #[allow(clippy::needless_lifetimes)]
+    pub fn peek_prev_mut<'cursor>(&'cursor mut self) -> Option<&'cursor mut T> {
+        unsafe {
+            let prev = (*self.cursor).prev;
+            if prev.is_null() {
+                None
+            } else {
+                Some(&mut (*prev).value)
+            }
+        }
+    }
     /// Returns the index of the cursor in the linked list. The front of the list has
     /// index zero and the back of the list has index `len - 1`.
     ///
@@ -737,21 +1645,183 @@ impl<'a, T> CursorMut<'a, T> {
     /// list.push_back(3);
     /// list.push_back(4);
     ///
-    /// if let Some(front) = list.cursor_mut_front() {
-    ///     assert_eq!(0, front.index());
-    ///     assert_eq!(1, front.next().unwrap().index());
-    /// }
-    /// # else { unreachable!(); }
-    /// if let Some(back) = list.cursor_mut_back() {
-    ///     assert_eq!(3, back.index());
-    ///     assert_eq!(2, back.prev().unwrap().index());
+    /// if let Some(front) = list.cursor_mut_front() {
+    ///     assert_eq!(0, front.index());
+    ///     assert_eq!(1, front.next().unwrap().index());
+    /// }
+    /// # else { unreachable!(); }
+    /// if let Some(back) = list.cursor_mut_back() {
+    ///     assert_eq!(3, back.index());
+    ///     assert_eq!(2, back.prev().unwrap().index());
+    /// }
+    /// # else { unreachable!(); }
+    /// ```
+    pub fn index(&self) -> usize {
+        self.index
+    }
+    /// Returns the length of the list. The list is borrowed by the cursor, so this is the
+    /// only way to get its length while the cursor is alive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<u32> = LinkedList::new();
+    /// list.extend(&[1, 2, 3]);
+    ///
+    /// if let Some(mut cursor) = list.cursor_mut_front() {
+    ///     assert_eq!(3, cursor.len());
+    ///     cursor.insert_next(4);
+    ///     assert_eq!(4, cursor.len());
+    /// }
+    /// # else { unreachable!(); }
+    /// ```
+    #[allow(clippy::len_without_is_empty)]
+    pub fn len(&self) -> usize {
+        self.list.len
+    }
+    /// Returns the number of elements after the cursor.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<u32> = LinkedList::new();
+    /// list.extend(&[1, 2, 3]);
+    ///
+    /// if let Some(mut cursor) = list.cursor_mut_front() {
+    ///     assert_eq!(2, cursor.remaining_forward());
+    ///     cursor.go_next();
+    ///     assert_eq!(1, cursor.remaining_forward());
+    /// }
+    /// # else { unreachable!(); }
+    /// ```
+    pub fn remaining_forward(&self) -> usize {
+        self.list.len - 1 - self.index
+    }
+    /// Returns the number of elements before the cursor, which is the same as its index.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<u32> = LinkedList::new();
+    /// list.extend(&[1, 2, 3]);
+    ///
+    /// if let Some(mut cursor) = list.cursor_mut_back() {
+    ///     assert_eq!(2, cursor.remaining_backward());
+    ///     cursor.insert_prev(0);
+    ///     assert_eq!(3, cursor.remaining_backward());
+    /// }
+    /// # else { unreachable!(); }
+    /// ```
+    pub fn remaining_backward(&self) -> usize {
+        self.index
+    }
+    /// Check that the index returned by [`index`] matches the position of the cursor, by
+    /// walking from the cursor to the front of the list.
+    ///
+    /// This is intended for debugging code that mutates the list through the cursor, and
+    /// takes time linear in the index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the tracked index is not the position of the current element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<u32> = LinkedList::new();
+    /// list.extend(&[1, 2, 3]);
+    ///
+    /// if let Some(mut cursor) = list.cursor_mut_at(1) {
+    ///     cursor.insert_prev(0);
+    ///     cursor.insert_next(4);
+    ///     cursor.assert_index_consistent();
+    ///     assert_eq!(2, cursor.index());
+    /// }
+    /// # else { unreachable!(); }
+    /// ```
+    ///
+    /// [`index`]: #method.index
+    pub fn assert_index_consistent(&self) {
+        let mut actual = 0;
+        let mut ptr = unsafe { (*self.cursor).prev };
+        while !ptr.is_null() {
+            actual += 1;
+            ptr = unsafe { (*ptr).prev };
+        }
+        assert!(
+            actual == self.index,
+            "cursor index (is {}) should be its position (is {})",
+            self.index,
+            actual
+        );
+    }
+    /// Returns `true` if the cursor points to the front of the list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<u32> = LinkedList::new();
+    /// list.push_back(1);
+    /// list.push_back(2);
+    ///
+    /// assert!(list.cursor_mut_front().unwrap().is_front());
+    /// assert!(!list.cursor_mut_back().unwrap().is_front());
+    /// ```
+    pub fn is_front(&self) -> bool {
+        unsafe { (*self.cursor).prev.is_null() }
+    }
+    /// Returns `true` if the cursor points to the back of the list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<u32> = LinkedList::new();
+    /// list.push_back(1);
+    /// list.push_back(2);
+    ///
+    /// assert!(!list.cursor_mut_front().unwrap().is_back());
+    /// assert!(list.cursor_mut_back().unwrap().is_back());
+    /// ```
+    pub fn is_back(&self) -> bool {
+        unsafe { (*self.cursor).next.is_null() }
+    }
+    /// Returns an immutable cursor to the same element. The returned cursor borrows
+    /// this cursor, so the `CursorMut` can't be used while the `CursorRef` is alive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<u32> = LinkedList::new();
+    /// list.extend(&[1, 2, 3]);
+    ///
+    /// if let Some(mut cursor) = list.cursor_mut_front() {
+    ///     cursor.go_next();
+    ///     let cursor_ref = cursor.as_cursor_ref();
+    ///     assert_eq!(&2, cursor_ref.get());
+    ///     assert_eq!(1, cursor_ref.index());
+    ///     assert_eq!(&1, cursor_ref.prev().unwrap().get());
     /// }
     /// # else { unreachable!(); }
     /// ```
-    pub fn index(&self) -> usize {
-        self.index
+    pub fn as_cursor_ref(&self) -> CursorRef<'_, T> {
+        CursorRef::create(self.list, self.cursor, self.index)
     }
-    /// Returns `true` if the cursor points to the front of the list.
+    /// Return `true` if the cursors point to the same element. Note that this does not
+    /// compare the actual values they point to.
     ///
     /// # Examples
     ///
@@ -759,16 +1829,19 @@ impl<'a, T> CursorMut<'a, T> {
     /// use linked_list::LinkedList;
     ///
     /// let mut list: LinkedList<u32> = LinkedList::new();
-    /// list.push_back(1);
-    /// list.push_back(2);
+    /// list.extend(&[1, 2, 3]);
     ///
-    /// assert!(list.cursor_mut_front().unwrap().is_front());
-    /// assert!(!list.cursor_mut_back().unwrap().is_front());
+    /// if let Some(mut cursor) = list.cursor_mut_front() {
+    ///     let front = cursor.as_cursor_ref();
+    ///     assert!(cursor.ptr_eq(front));
+    ///     assert!(!cursor.ptr_eq(front.next().unwrap()));
+    /// }
+    /// # else { unreachable!(); }
     /// ```
-    pub fn is_front(&self) -> bool {
-        unsafe { (*self.cursor).prev.is_null() }
+    pub fn ptr_eq(&self, other: CursorRef<T>) -> bool {
+        ptr::eq(self.cursor, other.cursor)
     }
-    /// Returns `true` if the cursor points to the back of the list.
+    /// Return `true` if the cursors point into the same list.
     ///
     /// # Examples
     ///
@@ -776,14 +1849,19 @@ impl<'a, T> CursorMut<'a, T> {
     /// use linked_list::LinkedList;
     ///
     /// let mut list: LinkedList<u32> = LinkedList::new();
-    /// list.push_back(1);
-    /// list.push_back(2);
+    /// list.extend(&[1, 2, 3]);
+    /// let other_list = list.clone();
     ///
-    /// assert!(!list.cursor_mut_front().unwrap().is_back());
-    /// assert!(list.cursor_mut_back().unwrap().is_back());
+    /// if let Some(mut cursor) = list.cursor_mut_front() {
+    ///     cursor.go_next();
+    ///     let front = cursor.as_cursor_ref().prev().unwrap();
+    ///     assert!(cursor.same_list(front));
+    ///     assert!(!cursor.same_list(other_list.cursor_ref_front().unwrap()));
+    /// }
+    /// # else { unreachable!(); }
     /// ```
-    pub fn is_back(&self) -> bool {
-        unsafe { (*self.cursor).next.is_null() }
+    pub fn same_list(&self, other: CursorRef<T>) -> bool {
+        ptr::eq(self.list.head, other.list_head)
     }
 
     /// Return an iterator from this element to the tail of the list.
@@ -888,10 +1966,428 @@ impl<'a, T> CursorMut<'a, T> {
         }
     }
 }
-unsafe impl<'a, T: Send> Send for CursorMut<'a, T> {}
-unsafe impl<'a, T: Sync> Sync for CursorMut<'a, T> {}
-impl<'a, T: fmt::Debug> fmt::Debug for CursorMut<'a, T> {
+unsafe impl<'a, T: Send, A: Allocator + Send> Send for CursorMut<'a, T, A> {}
+unsafe impl<'a, T: Sync, A: Allocator + Sync> Sync for CursorMut<'a, T, A> {}
+impl<'a, T: fmt::Debug, A: Allocator> fmt::Debug for CursorMut<'a, T, A> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        f.debug_struct("CursorMut")
+            .field("index", &self.index)
+            .field("value", self.get_ref())
+            .finish()
+    }
+}
+
+/// An unique cursor with mutable access to the `LinkedList`, which may also point to the
+/// "ghost" position.
+///
+/// Unlike a [`CursorMut`], this cursor can be created for an empty list. The ghost
+/// position sits both before the front and after the back of the list, so moving forward
+/// from the back or backward from the front moves the cursor to the ghost, and moving
+/// away from the ghost wraps around to the other end of the list. Inserting next to the
+/// ghost inserts at the corresponding end of the list.
+///
+/// The cursor is created using the [`cursor_mut`], [`cursor_mut_front_or_empty`] and
+/// [`cursor_mut_back_or_empty`] methods.
+///
+/// [`CursorMut`]: struct.CursorMut.html
+/// [`cursor_mut`]: struct.LinkedList.html#method.cursor_mut
+/// [`cursor_mut_front_or_empty`]: struct.LinkedList.html#method.cursor_mut_front_or_empty
+/// [`cursor_mut_back_or_empty`]: struct.LinkedList.html#method.cursor_mut_back_or_empty
+pub struct GhostCursorMut<'a, T: 'a, A: Allocator + 'a = Global> {
+    list: &'a mut LinkedList<T, A>,
+    // null when the cursor is at the ghost
+    cursor: *mut LinkedNode<T>,
+    index: usize,
+}
+
+impl<'a, T, A: Allocator> GhostCursorMut<'a, T, A> {
+    pub(crate) fn create(
+        list: &'a mut LinkedList<T, A>,
+        cursor: *mut LinkedNode<T>,
+        index: usize,
+    ) -> Self {
+        GhostCursorMut {
+            list,
+            cursor,
+            index,
+        }
+    }
+    /// Returns `true` if the cursor is at the ghost position.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<u32> = LinkedList::new();
+    /// assert!(list.cursor_mut_front_or_empty().is_ghost());
+    ///
+    /// list.push_back(1);
+    /// assert!(!list.cursor_mut_front_or_empty().is_ghost());
+    /// ```
+    pub fn is_ghost(&self) -> bool {
+        self.cursor.is_null()
+    }
+    /// Returns the index of the cursor, or `None` if the cursor is at the ghost position.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<u32> = LinkedList::new();
+    /// list.extend(&[1, 2]);
+    ///
+    /// let mut cursor = list.cursor_mut_back_or_empty();
+    /// assert_eq!(Some(1), cursor.index());
+    /// cursor.go_next();
+    /// assert_eq!(None, cursor.index());
+    /// ```
+    pub fn index(&self) -> Option<usize> {
+        if self.is_ghost() {
+            None
+        } else {
+            Some(self.index)
+        }
+    }
+    /// Returns the length of the list. The list is borrowed by the cursor, so this is the
+    /// only way to get its length while the cursor is alive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<u32> = LinkedList::new();
+    ///
+    /// let mut cursor = list.cursor_mut_front_or_empty();
+    /// assert_eq!(0, cursor.len());
+    /// cursor.insert_next(1);
+    /// assert_eq!(1, cursor.len());
+    /// ```
+    #[allow(clippy::len_without_is_empty)]
+    pub fn len(&self) -> usize {
+        self.list.len
+    }
+    /// Move the cursor to the next element. If the cursor is at the back of the list it
+    /// moves to the ghost, and if it is at the ghost it moves to the front of the list.
+    ///
+    /// If the list is empty, the cursor stays at the ghost.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<u32> = LinkedList::new();
+    /// list.extend(&[1, 2]);
+    ///
+    /// let mut cursor = list.cursor_mut_front_or_empty();
+    /// assert_eq!(Some(&1), cursor.get_ref());
+    /// cursor.go_next();
+    /// assert_eq!(Some(&2), cursor.get_ref());
+    /// cursor.go_next();
+    /// assert_eq!(None, cursor.get_ref());
+    /// cursor.go_next();
+    /// assert_eq!(Some(&1), cursor.get_ref());
+    /// ```
+    pub fn go_next(&mut self) {
+        if self.is_ghost() {
+            self.cursor = self.list.head;
+            self.index = 0;
+        } else {
+            self.cursor = unsafe { (*self.cursor).next };
+            self.index += 1;
+        }
+    }
+    /// Move the cursor to the previous element. If the cursor is at the front of the list
+    /// it moves to the ghost, and if it is at the ghost it moves to the back of the list.
+    ///
+    /// If the list is empty, the cursor stays at the ghost.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<u32> = LinkedList::new();
+    /// list.extend(&[1, 2]);
+    ///
+    /// let mut cursor = list.cursor_mut_front_or_empty();
+    /// cursor.go_prev();
+    /// assert!(cursor.is_ghost());
+    /// cursor.go_prev();
+    /// assert_eq!(Some(&2), cursor.get_ref());
+    /// assert_eq!(Some(1), cursor.index());
+    /// ```
+    pub fn go_prev(&mut self) {
+        if self.is_ghost() {
+            self.cursor = self.list.tail;
+            self.index = self.list.len.wrapping_sub(1);
+        } else {
+            self.cursor = unsafe { (*self.cursor).prev };
+            self.index = self.index.wrapping_sub(1);
+        }
+    }
+    /// Returns a mutable reference to the current element, or `None` if the cursor is at
+    /// the ghost position.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<u32> = LinkedList::new();
+    /// list.push_back(1);
+    ///
+    /// if let Some(value) = list.cursor_mut_front_or_empty().get() {
+    ///     *value = 2;
+    /// }
+    /// # else { unreachable!(); }
+    /// assert_eq!(list, vec![2]);
+    /// ```
+    #[allow(clippy::needless_lifetimes)]
+    pub fn get<'cursor>(&'cursor mut self) -> Option<&'cursor mut T> {
+        if self.is_ghost() {
+            None
+        } else {
+            Some(unsafe { &mut (*self.cursor).value })
+        }
+    }
+    /// Returns a reference to the current element, or `None` if the cursor is at the
+    /// ghost position.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<u32> = LinkedList::new();
+    /// assert_eq!(None, list.cursor_mut_front_or_empty().get_ref());
+    ///
+    /// list.push_back(1);
+    /// assert_eq!(Some(&1), list.cursor_mut_front_or_empty().get_ref());
+    /// ```
+    #[allow(clippy::needless_lifetimes)]
+    pub fn get_ref<'cursor>(&'cursor self) -> Option<&'cursor T> {
+        if self.is_ghost() {
+            None
+        } else {
+            Some(unsafe { &(*self.cursor).value })
+        }
+    }
+    /// Insert a new element after the cursor. If the cursor is at the ghost position, the
+    /// element is inserted at the front of the list. This method does not move the
+    /// cursor.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<u32> = LinkedList::new();
+    ///
+    /// {
+    ///     let mut cursor = list.cursor_mut_front_or_empty();
+    ///     cursor.insert_next(3);
+    ///     cursor.insert_next(1);
+    ///     cursor.go_next();
+    ///     cursor.insert_next(2);
+    ///     assert_eq!(Some(&1), cursor.get_ref());
+    /// }
+    ///
+    /// assert_eq!(list, vec![1, 2, 3]);
+    /// ```
+    pub fn insert_next(&mut self, value: T) {
+        if self.is_ghost() {
+            self.list.push_front(value);
+        } else {
+            CursorMut::create(self.list, self.cursor, self.index).insert_next(value);
+        }
+    }
+    /// Insert a new element before the cursor. If the cursor is at the ghost position,
+    /// the element is inserted at the back of the list. This method does not move the
+    /// cursor.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<u32> = LinkedList::new();
+    ///
+    /// {
+    ///     let mut cursor = list.cursor_mut_back_or_empty();
+    ///     cursor.insert_prev(1);
+    ///     cursor.insert_prev(3);
+    ///     cursor.go_prev();
+    ///     cursor.insert_prev(2);
+    ///     assert_eq!(Some(&3), cursor.get_ref());
+    ///     assert_eq!(Some(2), cursor.index());
+    /// }
+    ///
+    /// assert_eq!(list, vec![1, 2, 3]);
+    /// ```
+    pub fn insert_prev(&mut self, value: T) {
+        if self.is_ghost() {
+            self.list.push_back(value);
+        } else {
+            CursorMut::create(self.list, self.cursor, self.index).insert_prev(value);
+            self.index += 1;
+        }
+    }
+    /// Remove the current element and return it, moving the cursor to the next element,
+    /// or to the ghost position if the removed element was the back of the list. Returns
+    /// `None` without doing anything if the cursor is at the ghost position.
+    ///
+    /// The node of the removed element is kept as unused capacity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<u32> = LinkedList::new();
+    /// list.extend(&[1, 2]);
+    /// let capacity = list.capacity();
+    ///
+    /// {
+    ///     let mut cursor = list.cursor_mut_front_or_empty();
+    ///     assert_eq!(Some(1), cursor.remove_current());
+    ///     assert_eq!(Some(&2), cursor.get_ref());
+    ///     assert_eq!(Some(2), cursor.remove_current());
+    ///     assert!(cursor.is_ghost());
+    ///     assert_eq!(None, cursor.remove_current());
+    /// }
+    ///
+    /// assert!(list.is_empty());
+    /// assert_eq!(capacity, list.capacity());
+    /// ```
+    pub fn remove_current(&mut self) -> Option<T> {
+        if self.is_ghost() {
+            return None;
+        }
+        unsafe {
+            let next = (*self.cursor).next;
+            let value = self.list.remove_node(self.cursor);
+            self.cursor = next;
+            Some(value)
+        }
+    }
+}
+
+impl<'a, T> GhostCursorMut<'a, T> {
+    /// Move every element of `other` to just after the cursor, leaving `other` empty. If
+    /// the cursor is at the ghost position, the elements are moved to the front of the
+    /// list. This method does not move the cursor.
+    ///
+    /// The nodes and allocations of `other` are moved as in
+    /// [`CursorMut::splice_after_list`].
+    ///
+    /// [`CursorMut::splice_after_list`]: struct.CursorMut.html#method.splice_after_list
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<u32> = LinkedList::new();
+    /// let mut other: LinkedList<u32> = (1..4).collect();
+    ///
+    /// {
+    ///     let mut cursor = list.cursor_mut();
+    ///     cursor.splice_after_list(&mut other);
+    ///     cursor.go_next();
+    ///     assert_eq!(Some(&1), cursor.get_ref());
+    /// }
+    ///
+    /// assert_eq!(list, vec![1, 2, 3]);
+    /// assert!(other.is_empty());
+    /// assert_eq!(0, other.capacity());
+    /// ```
+    pub fn splice_after_list(&mut self, other: &mut LinkedList<T>) {
+        if !self.is_ghost() {
+            CursorMut::create(self.list, self.cursor, self.index).splice_after_list(other);
+        } else if self.list.is_empty() {
+            self.list.append(other);
+        } else {
+            let head = self.list.head;
+            CursorMut::create(self.list, head, 0).splice_before_list(other);
+        }
+    }
+    /// Move every element of `other` to just before the cursor, leaving `other` empty.
+    /// If the cursor is at the ghost position, the elements are moved to the back of the
+    /// list. This method does not move the cursor.
+    ///
+    /// The nodes and allocations of `other` are moved as in
+    /// [`CursorMut::splice_before_list`].
+    ///
+    /// [`CursorMut::splice_before_list`]: struct.CursorMut.html#method.splice_before_list
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<u32> = LinkedList::new();
+    /// list.extend(&[1, 4]);
+    /// let mut other: LinkedList<u32> = (2..4).collect();
+    ///
+    /// {
+    ///     let mut cursor = list.cursor_mut_back_or_empty();
+    ///     cursor.splice_before_list(&mut other);
+    ///     assert_eq!(Some(3), cursor.index());
+    ///     assert_eq!(Some(&4), cursor.get_ref());
+    /// }
+    ///
+    /// assert_eq!(list, vec![1, 2, 3, 4]);
+    /// ```
+    pub fn splice_before_list(&mut self, other: &mut LinkedList<T>) {
+        if self.is_ghost() {
+            self.list.append(other);
+        } else {
+            let len = other.len;
+            CursorMut::create(self.list, self.cursor, self.index).splice_before_list(other);
+            self.index += len;
+        }
+    }
+}
+
+impl<'a, T, A: Allocator> GhostCursorMut<'a, T, A> {
+    /// Converts this cursor into a [`CursorMut`] at the same element, or returns `None`
+    /// if the cursor is at the ghost position.
+    ///
+    /// [`CursorMut`]: struct.CursorMut.html
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<u32> = LinkedList::new();
+    ///
+    /// let mut cursor = list.cursor_mut_front_or_empty();
+    /// cursor.insert_next(1);
+    /// cursor.go_next();
+    ///
+    /// let cursor = cursor.into_cursor_mut().unwrap();
+    /// assert_eq!(&1, cursor.get_ref());
+    /// ```
+    pub fn into_cursor_mut(self) -> Option<CursorMut<'a, T, A>> {
+        if self.is_ghost() {
+            None
+        } else {
+            Some(CursorMut::create(self.list, self.cursor, self.index))
+        }
+    }
+}
+unsafe impl<'a, T: Send, A: Allocator + Send> Send for GhostCursorMut<'a, T, A> {}
+unsafe impl<'a, T: Sync, A: Allocator + Sync> Sync for GhostCursorMut<'a, T, A> {}
+impl<'a, T: fmt::Debug, A: Allocator> fmt::Debug for GhostCursorMut<'a, T, A> {
     fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
-        f.debug_tuple("CursorMut").field(self.get_ref()).finish()
+        f.debug_struct("GhostCursorMut")
+            .field("index", &self.index())
+            .field("value", &self.get_ref())
+            .finish()
     }
 }
@@ -1,12 +1,13 @@
 //! This module provides various iterators over the linked list.
 use super::*;
 
-use std::fmt;
+use core::fmt;
 #[cfg(feature = "nightly")]
-use std::iter::TrustedLen;
-use std::iter::{DoubleEndedIterator, ExactSizeIterator, FusedIterator};
-use std::marker::PhantomData;
-use std::ptr;
+use core::iter::TrustedLen;
+use core::iter::{DoubleEndedIterator, ExactSizeIterator, FusedIterator};
+use core::marker::PhantomData;
+#[cfg(feature = "nightly")]
+use core::ops::Try;
 
 /// An iterator over borrowed values from a linked list.
 pub struct Iter<'a, T: 'a> {
@@ -48,6 +49,48 @@ impl<'a, T> Iterator for Iter<'a, T> {
             None
         }
     }
+    fn nth(&mut self, n: usize) -> Option<&'a T> {
+        if n >= self.len {
+            self.len = 0;
+            return None;
+        }
+        for _ in 0..n {
+            self.head = unsafe { (*self.head).next };
+        }
+        self.len -= n;
+        self.next()
+    }
+    fn fold<B, F>(self, init: B, mut f: F) -> B
+    where
+        F: FnMut(B, &'a T) -> B,
+    {
+        let mut acc = init;
+        let mut ptr = self.head;
+        for _ in 0..self.len {
+            unsafe {
+                acc = f(acc, &(*ptr).value);
+                ptr = (*ptr).next;
+            }
+        }
+        acc
+    }
+    #[cfg(feature = "nightly")]
+    fn try_fold<B, F, R>(&mut self, init: B, mut f: F) -> R
+    where
+        F: FnMut(B, &'a T) -> R,
+        R: Try<Output = B>,
+    {
+        let mut acc = init;
+        while self.len > 0 {
+            unsafe {
+                let value = &(*self.head).value;
+                self.head = (*self.head).next;
+                self.len -= 1;
+                acc = f(acc, value)?;
+            }
+        }
+        R::from_output(acc)
+    }
 }
 impl<'a, T> DoubleEndedIterator for Iter<'a, T> {
     fn next_back(&mut self) -> Option<&'a T> {
@@ -63,21 +106,62 @@ impl<'a, T> DoubleEndedIterator for Iter<'a, T> {
             None
         }
     }
+    fn nth_back(&mut self, n: usize) -> Option<&'a T> {
+        if n >= self.len {
+            self.len = 0;
+            return None;
+        }
+        for _ in 0..n {
+            self.tail = unsafe { (*self.tail).prev };
+        }
+        self.len -= n;
+        self.next_back()
+    }
+    fn rfold<B, F>(self, init: B, mut f: F) -> B
+    where
+        F: FnMut(B, &'a T) -> B,
+    {
+        let mut acc = init;
+        let mut ptr = self.tail;
+        for _ in 0..self.len {
+            unsafe {
+                acc = f(acc, &(*ptr).value);
+                ptr = (*ptr).prev;
+            }
+        }
+        acc
+    }
+    #[cfg(feature = "nightly")]
+    fn try_rfold<B, F, R>(&mut self, init: B, mut f: F) -> R
+    where
+        F: FnMut(B, &'a T) -> R,
+        R: Try<Output = B>,
+    {
+        let mut acc = init;
+        while self.len > 0 {
+            unsafe {
+                let value = &(*self.tail).value;
+                self.tail = (*self.tail).prev;
+                self.len -= 1;
+                acc = f(acc, value)?;
+            }
+        }
+        R::from_output(acc)
+    }
 }
 impl<'a, T> FusedIterator for Iter<'a, T> {}
 impl<'a, T> ExactSizeIterator for Iter<'a, T> {
     fn len(&self) -> usize {
         self.len
     }
+    #[cfg(feature = "nightly")]
+    fn is_empty(&self) -> bool {
+        self.len == 0
+    }
 }
 impl<'a, T> Clone for Iter<'a, T> {
     fn clone(&self) -> Self {
-        Iter {
-            head: self.head,
-            tail: self.tail,
-            len: self.len,
-            marker: self.marker
-        }
+        *self
     }
 }
 impl<'a, T> Copy for Iter<'a, T> {}
@@ -96,6 +180,101 @@ impl<'a, T: fmt::Debug> fmt::Debug for Iter<'a, T> {
     }
 }
 
+/// An iterator over borrowed values from a linked list, from the back to the front.
+///
+/// This is created by [`iter_rev`].
+///
+/// [`iter_rev`]: struct.LinkedList.html#method.iter_rev
+pub struct IterRev<'a, T: 'a> {
+    pub(crate) iter: Iter<'a, T>,
+}
+#[cfg(feature = "nightly")]
+unsafe impl<'a, T> TrustedLen for IterRev<'a, T> {}
+impl<'a, T> Iterator for IterRev<'a, T> {
+    type Item = &'a T;
+    fn next(&mut self) -> Option<&'a T> {
+        self.iter.next_back()
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+    fn count(self) -> usize {
+        self.iter.len
+    }
+    fn last(mut self) -> Option<&'a T> {
+        self.iter.next()
+    }
+    fn nth(&mut self, n: usize) -> Option<&'a T> {
+        self.iter.nth_back(n)
+    }
+    fn fold<B, F>(self, init: B, f: F) -> B
+    where
+        F: FnMut(B, &'a T) -> B,
+    {
+        self.iter.rfold(init, f)
+    }
+    #[cfg(feature = "nightly")]
+    fn try_fold<B, F, R>(&mut self, init: B, f: F) -> R
+    where
+        F: FnMut(B, &'a T) -> R,
+        R: Try<Output = B>,
+    {
+        self.iter.try_rfold(init, f)
+    }
+}
+impl<'a, T> DoubleEndedIterator for IterRev<'a, T> {
+    fn next_back(&mut self) -> Option<&'a T> {
+        self.iter.next()
+    }
+    fn nth_back(&mut self, n: usize) -> Option<&'a T> {
+        self.iter.nth(n)
+    }
+    fn rfold<B, F>(self, init: B, f: F) -> B
+    where
+        F: FnMut(B, &'a T) -> B,
+    {
+        self.iter.fold(init, f)
+    }
+    #[cfg(feature = "nightly")]
+    fn try_rfold<B, F, R>(&mut self, init: B, f: F) -> R
+    where
+        F: FnMut(B, &'a T) -> R,
+        R: Try<Output = B>,
+    {
+        self.iter.try_fold(init, f)
+    }
+}
+impl<'a, T> FusedIterator for IterRev<'a, T> {}
+impl<'a, T> ExactSizeIterator for IterRev<'a, T> {
+    fn len(&self) -> usize {
+        self.iter.len
+    }
+    #[cfg(feature = "nightly")]
+    fn is_empty(&self) -> bool {
+        self.iter.len == 0
+    }
+}
+impl<'a, T> Clone for IterRev<'a, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl<'a, T> Copy for IterRev<'a, T> {}
+impl<'a, T: fmt::Debug> fmt::Debug for IterRev<'a, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        f.write_str("LinkedList::Iter")?;
+        let mut out = f.debug_list();
+        let mut ptr = self.iter.tail;
+        for _ in 0..self.iter.len {
+            unsafe {
+                out.entry(&(*ptr).value);
+                ptr = (*ptr).prev;
+            }
+        }
+        out.finish()
+    }
+}
+
 /// An iterator over mutably borrowed values from a linked list.
 pub struct IterMut<'a, T: 'a> {
     pub(crate) head: *mut LinkedNode<T>,
@@ -107,6 +286,36 @@ pub struct IterMut<'a, T: 'a> {
 unsafe impl<'a, T> TrustedLen for IterMut<'a, T> {}
 unsafe impl<'a, T: Send> Send for IterMut<'a, T> {}
 unsafe impl<'a, T: Sync> Sync for IterMut<'a, T> {}
+impl<'a, T> IterMut<'a, T> {
+    /// Provides an immutable iterator over the elements that have not been yielded yet,
+    /// without consuming them. The returned iterator borrows this iterator, so it can't
+    /// be advanced while the `Iter` is alive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<u32> = LinkedList::new();
+    /// list.extend(&[1, 2, 3, 4]);
+    ///
+    /// let mut iter = list.iter_mut();
+    /// assert_eq!(Some(&mut 1), iter.next());
+    /// assert_eq!(Some(&mut 4), iter.next_back());
+    ///
+    /// let remaining: Vec<&u32> = iter.as_ref_iter().collect();
+    /// assert_eq!(remaining, [&2, &3]);
+    /// assert_eq!(Some(&mut 2), iter.next());
+    /// ```
+    pub fn as_ref_iter(&self) -> Iter<'_, T> {
+        Iter {
+            head: self.head,
+            tail: self.tail,
+            len: self.len,
+            marker: PhantomData,
+        }
+    }
+}
 impl<'a, T> Iterator for IterMut<'a, T> {
     type Item = &'a mut T;
     fn next(&mut self) -> Option<&'a mut T> {
@@ -136,6 +345,48 @@ impl<'a, T> Iterator for IterMut<'a, T> {
             None
         }
     }
+    fn nth(&mut self, n: usize) -> Option<&'a mut T> {
+        if n >= self.len {
+            self.len = 0;
+            return None;
+        }
+        for _ in 0..n {
+            self.head = unsafe { (*self.head).next };
+        }
+        self.len -= n;
+        self.next()
+    }
+    fn fold<B, F>(self, init: B, mut f: F) -> B
+    where
+        F: FnMut(B, &'a mut T) -> B,
+    {
+        let mut acc = init;
+        let mut ptr = self.head;
+        for _ in 0..self.len {
+            unsafe {
+                acc = f(acc, &mut (*ptr).value);
+                ptr = (*ptr).next;
+            }
+        }
+        acc
+    }
+    #[cfg(feature = "nightly")]
+    fn try_fold<B, F, R>(&mut self, init: B, mut f: F) -> R
+    where
+        F: FnMut(B, &'a mut T) -> R,
+        R: Try<Output = B>,
+    {
+        let mut acc = init;
+        while self.len > 0 {
+            unsafe {
+                let value = &mut (*self.head).value;
+                self.head = (*self.head).next;
+                self.len -= 1;
+                acc = f(acc, value)?;
+            }
+        }
+        R::from_output(acc)
+    }
 }
 impl<'a, T> DoubleEndedIterator for IterMut<'a, T> {
     fn next_back(&mut self) -> Option<&'a mut T> {
@@ -151,12 +402,58 @@ impl<'a, T> DoubleEndedIterator for IterMut<'a, T> {
             None
         }
     }
+    fn nth_back(&mut self, n: usize) -> Option<&'a mut T> {
+        if n >= self.len {
+            self.len = 0;
+            return None;
+        }
+        for _ in 0..n {
+            self.tail = unsafe { (*self.tail).prev };
+        }
+        self.len -= n;
+        self.next_back()
+    }
+    fn rfold<B, F>(self, init: B, mut f: F) -> B
+    where
+        F: FnMut(B, &'a mut T) -> B,
+    {
+        let mut acc = init;
+        let mut ptr = self.tail;
+        for _ in 0..self.len {
+            unsafe {
+                acc = f(acc, &mut (*ptr).value);
+                ptr = (*ptr).prev;
+            }
+        }
+        acc
+    }
+    #[cfg(feature = "nightly")]
+    fn try_rfold<B, F, R>(&mut self, init: B, mut f: F) -> R
+    where
+        F: FnMut(B, &'a mut T) -> R,
+        R: Try<Output = B>,
+    {
+        let mut acc = init;
+        while self.len > 0 {
+            unsafe {
+                let value = &mut (*self.tail).value;
+                self.tail = (*self.tail).prev;
+                self.len -= 1;
+                acc = f(acc, value)?;
+            }
+        }
+        R::from_output(acc)
+    }
 }
 impl<'a, T> FusedIterator for IterMut<'a, T> {}
 impl<'a, T> ExactSizeIterator for IterMut<'a, T> {
     fn len(&self) -> usize {
         self.len
     }
+    #[cfg(feature = "nightly")]
+    fn is_empty(&self) -> bool {
+        self.len == 0
+    }
 }
 impl<'a, T: fmt::Debug> fmt::Debug for IterMut<'a, T> {
     fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
@@ -176,93 +473,391 @@ impl<'a, T: fmt::Debug> fmt::Debug for IterMut<'a, T> {
     }
 }
 
-/// An iterator over values from a linked list.
-pub struct IntoIter<T> {
-    pub(crate) head: *mut LinkedNode<T>,
-    pub(crate) tail: *mut LinkedNode<T>,
-    pub(crate) len: usize,
-    pub(crate) allocations: Vec<(*mut LinkedNode<T>, usize)>,
+/// An iterator over mutably borrowed values from a linked list, from the back to the
+/// front.
+///
+/// This is created by [`iter_mut_rev`].
+///
+/// [`iter_mut_rev`]: struct.LinkedList.html#method.iter_mut_rev
+pub struct IterMutRev<'a, T: 'a> {
+    pub(crate) iter: IterMut<'a, T>,
 }
 #[cfg(feature = "nightly")]
-unsafe impl<T> TrustedLen for IntoIter<T> {}
-unsafe impl<T: Send> Send for IntoIter<T> {}
-unsafe impl<T: Sync> Sync for IntoIter<T> {}
-impl<T> Iterator for IntoIter<T> {
-    type Item = T;
-    fn next(&mut self) -> Option<T> {
-        if self.len > 0 {
-            debug_assert!(!self.head.is_null());
+unsafe impl<'a, T> TrustedLen for IterMutRev<'a, T> {}
+impl<'a, T> Iterator for IterMutRev<'a, T> {
+    type Item = &'a mut T;
+    fn next(&mut self) -> Option<&'a mut T> {
+        self.iter.next_back()
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+    fn count(self) -> usize {
+        self.iter.len
+    }
+    fn last(mut self) -> Option<&'a mut T> {
+        self.iter.next()
+    }
+    fn nth(&mut self, n: usize) -> Option<&'a mut T> {
+        self.iter.nth_back(n)
+    }
+    fn fold<B, F>(self, init: B, f: F) -> B
+    where
+        F: FnMut(B, &'a mut T) -> B,
+    {
+        self.iter.rfold(init, f)
+    }
+    #[cfg(feature = "nightly")]
+    fn try_fold<B, F, R>(&mut self, init: B, f: F) -> R
+    where
+        F: FnMut(B, &'a mut T) -> R,
+        R: Try<Output = B>,
+    {
+        self.iter.try_rfold(init, f)
+    }
+}
+impl<'a, T> DoubleEndedIterator for IterMutRev<'a, T> {
+    fn next_back(&mut self) -> Option<&'a mut T> {
+        self.iter.next()
+    }
+    fn nth_back(&mut self, n: usize) -> Option<&'a mut T> {
+        self.iter.nth(n)
+    }
+    fn rfold<B, F>(self, init: B, f: F) -> B
+    where
+        F: FnMut(B, &'a mut T) -> B,
+    {
+        self.iter.fold(init, f)
+    }
+    #[cfg(feature = "nightly")]
+    fn try_rfold<B, F, R>(&mut self, init: B, f: F) -> R
+    where
+        F: FnMut(B, &'a mut T) -> R,
+        R: Try<Output = B>,
+    {
+        self.iter.try_fold(init, f)
+    }
+}
+impl<'a, T> FusedIterator for IterMutRev<'a, T> {}
+impl<'a, T> ExactSizeIterator for IterMutRev<'a, T> {
+    fn len(&self) -> usize {
+        self.iter.len
+    }
+    #[cfg(feature = "nightly")]
+    fn is_empty(&self) -> bool {
+        self.iter.len == 0
+    }
+}
+impl<'a, T: fmt::Debug> fmt::Debug for IterMutRev<'a, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        f.write_str("LinkedList::IterMut")?;
+        let mut out = f.debug_list();
+        let mut ptr = self.iter.tail;
+        for _ in 0..self.iter.len {
             unsafe {
-                let value = ptr::read(&(*self.head).value);
-                self.head = (*self.head).next;
-                self.len -= 1;
-                Some(value)
+                // this only prints the values not yet returned, see IterMut
+                out.entry(&(*ptr).value);
+                ptr = (*ptr).prev;
             }
-        } else {
+        }
+        out.finish()
+    }
+}
+
+/// An iterator over groups of borrowed values from a linked list.
+///
+/// Each chunk is a `Vec` of references to `size` consecutive elements, except the last
+/// chunk which may be shorter. This is created by [`chunks`].
+///
+/// [`chunks`]: struct.LinkedList.html#method.chunks
+pub struct Chunks<'a, T: 'a> {
+    pub(crate) iter: Iter<'a, T>,
+    pub(crate) size: usize,
+}
+impl<'a, T> Iterator for Chunks<'a, T> {
+    type Item = Vec<&'a T>;
+    fn next(&mut self) -> Option<Vec<&'a T>> {
+        if self.iter.len == 0 {
             None
+        } else {
+            let size = cmp::min(self.size, self.iter.len);
+            let mut chunk = Vec::with_capacity(size);
+            chunk.extend(self.iter.by_ref().take(size));
+            Some(chunk)
         }
     }
     fn size_hint(&self) -> (usize, Option<usize>) {
-        (self.len, Some(self.len))
+        let len = self.len();
+        (len, Some(len))
     }
     fn count(self) -> usize {
-        self.len
+        self.len()
     }
-    fn last(self) -> Option<T> {
-        if self.len > 0 {
-            debug_assert!(!self.tail.is_null());
-            unsafe { Some(ptr::read(&(*self.tail).value)) }
+}
+impl<'a, T> FusedIterator for Chunks<'a, T> {}
+impl<'a, T> ExactSizeIterator for Chunks<'a, T> {
+    fn len(&self) -> usize {
+        let full_chunks = self.iter.len / self.size;
+        if full_chunks * self.size == self.iter.len {
+            full_chunks
         } else {
-            None
+            full_chunks + 1
         }
     }
 }
-impl<T> DoubleEndedIterator for IntoIter<T> {
-    fn next_back(&mut self) -> Option<T> {
-        if self.len > 0 {
-            debug_assert!(!self.tail.is_null());
-            unsafe {
-                let value = ptr::read(&(*self.tail).value);
-                self.tail = (*self.tail).prev;
-                self.len -= 1;
-                Some(value)
-            }
-        } else {
+impl<'a, T> Clone for Chunks<'a, T> {
+    fn clone(&self) -> Self {
+        Chunks {
+            iter: self.iter,
+            size: self.size,
+        }
+    }
+}
+impl<'a, T: fmt::Debug> fmt::Debug for Chunks<'a, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        f.debug_struct("Chunks")
+            .field("size", &self.size)
+            .field("iter", &self.iter)
+            .finish()
+    }
+}
+
+/// An iterator over overlapping groups of borrowed values from a linked list.
+///
+/// Each window is a `Vec` of references to `size` consecutive elements, and each window
+/// starts one element after the previous one. This is created by [`windows`].
+///
+/// [`windows`]: struct.LinkedList.html#method.windows
+pub struct Windows<'a, T: 'a> {
+    pub(crate) iter: Iter<'a, T>,
+    pub(crate) size: usize,
+}
+impl<'a, T> Iterator for Windows<'a, T> {
+    type Item = Vec<&'a T>;
+    fn next(&mut self) -> Option<Vec<&'a T>> {
+        if self.iter.len < self.size {
             None
+        } else {
+            let mut window = Vec::with_capacity(self.size);
+            window.extend(self.iter.take(self.size));
+            self.iter.next();
+            Some(window)
         }
     }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+    fn count(self) -> usize {
+        self.len()
+    }
 }
-impl<T> FusedIterator for IntoIter<T> {}
-impl<T> ExactSizeIterator for IntoIter<T> {
+impl<'a, T> FusedIterator for Windows<'a, T> {}
+impl<'a, T> ExactSizeIterator for Windows<'a, T> {
     fn len(&self) -> usize {
-        self.len
+        (self.iter.len + 1).saturating_sub(self.size)
+    }
+}
+impl<'a, T> Clone for Windows<'a, T> {
+    fn clone(&self) -> Self {
+        Windows {
+            iter: self.iter,
+            size: self.size,
+        }
+    }
+}
+impl<'a, T: fmt::Debug> fmt::Debug for Windows<'a, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        f.debug_struct("Windows")
+            .field("size", &self.size)
+            .field("iter", &self.iter)
+            .finish()
     }
 }
-impl<T> Drop for IntoIter<T> {
-    fn drop(&mut self) {
-        unsafe {
-            // drop remaining elements
-            while let Some(_) = self.next() {}
 
-            // deallocate memory
-            for &(vecptr, capacity) in &self.allocations {
-                let vec = Vec::from_raw_parts(vecptr, 0, capacity);
-                drop(vec);
-            }
+/// An endless iterator over borrowed values from a linked list, which starts over at
+/// the front after yielding the back element. It never yields anything if the list is
+/// empty. This is created by [`cycle_iter`].
+///
+/// [`cycle_iter`]: struct.LinkedList.html#method.cycle_iter
+pub struct Cycle<'a, T: 'a> {
+    pub(crate) front: Option<CursorRef<'a, T>>,
+    pub(crate) cursor: Option<CursorRef<'a, T>>,
+}
+impl<'a, T> Iterator for Cycle<'a, T> {
+    type Item = &'a T;
+    fn next(&mut self) -> Option<&'a T> {
+        let cursor = self.cursor?;
+        // wrap around to the front when reaching the back
+        self.cursor = cursor.next().or(self.front);
+        Some(cursor.get())
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.cursor.is_some() {
+            (usize::MAX, None)
+        } else {
+            (0, Some(0))
+        }
+    }
+}
+impl<'a, T> FusedIterator for Cycle<'a, T> {}
+impl<'a, T> Clone for Cycle<'a, T> {
+    fn clone(&self) -> Self {
+        Cycle {
+            front: self.front,
+            cursor: self.cursor,
         }
     }
 }
-impl<T: fmt::Debug> fmt::Debug for IntoIter<T> {
+impl<'a, T: fmt::Debug> fmt::Debug for Cycle<'a, T> {
     fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
-        f.write_str("LinkedList::IntoIter")?;
-        let mut out = f.debug_list();
-        let mut ptr = self.head;
-        for _ in 0..self.len {
-            unsafe {
-                out.entry(&(*ptr).value);
-                ptr = (*ptr).next;
-            }
+        f.debug_struct("Cycle")
+            .field("cursor", &self.cursor)
+            .finish()
+    }
+}
+
+/// An iterator over values from a linked list.
+pub struct IntoIter<T, A: Allocator = Global> {
+    pub(crate) list: LinkedList<T, A>,
+}
+#[cfg(feature = "nightly")]
+unsafe impl<T, A: Allocator> TrustedLen for IntoIter<T, A> {}
+impl<T, A: Allocator> IntoIter<T, A> {
+    /// Convert the remaining elements back into a `LinkedList`.
+    ///
+    /// The list reuses the nodes and allocations of the original list, so this doesn't
+    /// allocate and the capacity of the original list is preserved.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<u32> = LinkedList::new();
+    /// list.extend(&[1, 2, 3, 4]);
+    /// let capacity = list.capacity();
+    ///
+    /// let mut iter = list.into_iter();
+    /// assert_eq!(Some(1), iter.next());
+    /// assert_eq!(Some(2), iter.next());
+    ///
+    /// let list = iter.into_list();
+    /// assert_eq!(list, vec![3, 4]);
+    /// assert_eq!(capacity, list.capacity());
+    /// ```
+    pub fn into_list(self) -> LinkedList<T, A> {
+        self.list
+    }
+    /// Provides an iterator over the elements that have not been yielded yet, without
+    /// consuming them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<u32> = LinkedList::new();
+    /// list.extend(&[1, 2, 3, 4]);
+    ///
+    /// let mut iter = list.into_iter();
+    /// assert_eq!(Some(1), iter.next());
+    /// assert_eq!(Some(4), iter.next_back());
+    ///
+    /// let remaining: Vec<&u32> = iter.as_iter().collect();
+    /// assert_eq!(remaining, [&2, &3]);
+    /// assert_eq!(Some(2), iter.next());
+    /// ```
+    pub fn as_iter(&self) -> Iter<'_, T> {
+        self.list.iter()
+    }
+}
+impl<T: Clone, A: Allocator + Clone> Clone for IntoIter<T, A> {
+    fn clone(&self) -> Self {
+        IntoIter {
+            list: self.list.clone(),
         }
-        out.finish()
+    }
+}
+impl<T, A: Allocator> Iterator for IntoIter<T, A> {
+    type Item = T;
+    fn next(&mut self) -> Option<T> {
+        self.list.pop_front()
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.list.len, Some(self.list.len))
+    }
+    fn count(self) -> usize {
+        self.list.len
+    }
+    fn last(mut self) -> Option<T> {
+        self.list.pop_back()
+    }
+    fn nth(&mut self, n: usize) -> Option<T> {
+        if n >= self.list.len {
+            self.list.clear();
+            return None;
+        }
+        for _ in 0..n {
+            drop(self.list.pop_front());
+        }
+        self.list.pop_front()
+    }
+    #[cfg(feature = "nightly")]
+    fn try_fold<B, F, R>(&mut self, init: B, mut f: F) -> R
+    where
+        F: FnMut(B, T) -> R,
+        R: Try<Output = B>,
+    {
+        // the values are removed one at a time, so the rest are dropped with the iterator
+        let mut acc = init;
+        while let Some(value) = self.list.pop_front() {
+            acc = f(acc, value)?;
+        }
+        R::from_output(acc)
+    }
+}
+impl<T, A: Allocator> DoubleEndedIterator for IntoIter<T, A> {
+    fn next_back(&mut self) -> Option<T> {
+        self.list.pop_back()
+    }
+    fn nth_back(&mut self, n: usize) -> Option<T> {
+        if n >= self.list.len {
+            self.list.clear();
+            return None;
+        }
+        for _ in 0..n {
+            drop(self.list.pop_back());
+        }
+        self.list.pop_back()
+    }
+    #[cfg(feature = "nightly")]
+    fn try_rfold<B, F, R>(&mut self, init: B, mut f: F) -> R
+    where
+        F: FnMut(B, T) -> R,
+        R: Try<Output = B>,
+    {
+        // the values are removed one at a time, so the rest are dropped with the iterator
+        let mut acc = init;
+        while let Some(value) = self.list.pop_back() {
+            acc = f(acc, value)?;
+        }
+        R::from_output(acc)
+    }
+}
+impl<T, A: Allocator> FusedIterator for IntoIter<T, A> {}
+impl<T, A: Allocator> ExactSizeIterator for IntoIter<T, A> {
+    fn len(&self) -> usize {
+        self.list.len
+    }
+    #[cfg(feature = "nightly")]
+    fn is_empty(&self) -> bool {
+        self.list.is_empty()
+    }
+}
+impl<T: fmt::Debug, A: Allocator> fmt::Debug for IntoIter<T, A> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        f.write_str("LinkedList::IntoIter")?;
+        f.debug_list().entries(self.list.iter()).finish()
     }
 }
@@ -1,4 +1,9 @@
-#![cfg_attr(feature = "nightly", feature(trusted_len))]
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+#![cfg_attr(
+    feature = "nightly",
+    feature(trusted_len, exact_size_is_empty, try_trait_v2)
+)]
+#![cfg_attr(feature = "allocator_api", feature(allocator_api))]
 
 //! This crate provides a linked list with a special allocation method, allowing
 //! allocations of several nodes in one allocation.
@@ -21,17 +26,52 @@
 //! items.  Note that a [`CursorMut`] doesn't allow obtaining simultaneous mutable
 //! references to different elements like a mutable iterator does.
 //!
+//! Both cursor types always point to an element, so they can't be created for an empty
+//! list.  If you need to insert into a list that might be empty through a cursor, the
+//! [`GhostCursorMut`] can also point to a "ghost" position between the back and the
+//! front of the list.
+//!
 //! Note that the list can also be modified using the [`retain_map`], [`retain_mut`] and
 //! [`retain`] methods.
 //!
+//! # Node ownership
+//!
+//! Every list owns the allocations its nodes live in, and frees them when it's dropped,
+//! so a node can only be used by the list that owns its allocation.  Methods that move
+//! some of the elements to another list, such as [`split_into`] and [`partition`],
+//! therefore move the values into nodes of the receiving list, and the nodes they leave
+//! behind become unused capacity of the list they came from.  The exception is
+//! [`append`] and the methods built on it, which move every allocation of the other list
+//! along with its nodes.
+//!
 //! # Features
 //!
+//! The crate only needs `core` and `alloc`, so it can be used in `no_std` environments
+//! by disabling the default `std` feature.
+//!
 //! This crate provides a `serde` feature which implements [`Serialize`] and
-//! [`Deserialize`] on `LinkedList`.
+//! [`Deserialize`] on `LinkedList`. The list is serialized as a sequence, but the
+//! [`serde_chunk_size`] module can be used to also store the chunk size.
+//!
+//! A `rayon` feature is provided, which implements [`IntoParallelIterator`] on
+//! `LinkedList` and references to it, allowing the use of `par_iter` and `par_iter_mut`.
+//! Since a linked list can't be split cheaply, the elements (or references to them) are
+//! first collected into a `Vec` in a sequential `O(n)` pass, which is then iterated in
+//! parallel.
 //!
 //! A `nightly` feature is provided, which currently just adds implementations of
-//! [`TrustedLen`] on iterators, but it may provide more nightly-only features in the
-//! future.
+//! [`TrustedLen`], `ExactSizeIterator::is_empty` and `try_fold` on iterators, but it may
+//! provide more nightly-only features in the future.
+//!
+//! An `allocator_api` feature is provided, which requires nightly and allows the chunks
+//! of nodes to be allocated with any [`Allocator`] by creating the list with [`new_in`]
+//! or one of the other `_in` constructors. Without this feature, the allocator of a list
+//! is always [`Global`]. Methods such as [`append`] move allocations between lists, so
+//! they are only available for lists using [`Global`], which can free memory allocated
+//! for any other list. Two lists with the same allocator type can still use different
+//! allocator instances, so for other allocators the elements can be moved with
+//! [`append_elements_only`] instead, or the allocations with the unsafe
+//! [`append_unchecked`] if the allocators are known to be compatible.
 //!
 //! # Examples
 //!
@@ -50,24 +90,49 @@
 //! [`TrustedLen`]: https://doc.rust-lang.org/std/iter/trait.TrustedLen.html
 //! [`Serialize`]: https://docs.serde.rs/serde/trait.Serialize.html
 //! [`Deserialize`]: https://docs.serde.rs/serde/trait.Deserialize.html
+//! [`IntoParallelIterator`]: https://docs.rs/rayon/1/rayon/iter/trait.IntoParallelIterator.html
+//! [`serde_chunk_size`]: serde_chunk_size/index.html
+//! [`Allocator`]: https://doc.rust-lang.org/std/alloc/trait.Allocator.html
+//! [`Global`]: struct.Global.html
+//! [`new_in`]: struct.LinkedList.html#method.new_in
+//! [`append`]: struct.LinkedList.html#method.append
+//! [`append_elements_only`]: struct.LinkedList.html#method.append_elements_only
+//! [`append_unchecked`]: struct.LinkedList.html#method.append_unchecked
 //! [`CursorRef`]: struct.CursorRef.html
 //! [`CursorMut`]: struct.CursorMut.html
+//! [`GhostCursorMut`]: struct.GhostCursorMut.html
 //! [`retain_map`]: struct.LinkedList.html#method.retain_map
 //! [`retain_mut`]: struct.LinkedList.html#method.retain_mut
 //! [`retain`]: struct.LinkedList.html#method.retain
+//! [`split_into`]: struct.LinkedList.html#method.split_into
+//! [`partition`]: struct.LinkedList.html#method.partition
 
-use std::cmp::Ordering;
-use std::fmt;
-use std::hash::{Hash, Hasher};
-use std::iter::{Extend, FromIterator, IntoIterator};
-use std::marker::PhantomData;
-use std::mem;
-use std::ptr;
+#[cfg(any(feature = "std", test))]
+extern crate core;
+#[macro_use]
+extern crate alloc;
 
+use alloc::collections::VecDeque;
+use alloc::vec::Vec;
+use core::alloc::Layout;
+use core::cmp::{self, Ordering};
+use core::convert::Infallible;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::iter::{Extend, FromIterator, IntoIterator};
+use core::marker::PhantomData;
+use core::mem;
+use core::ops::{Bound, RangeBounds};
+use core::ptr;
+
+mod allocator;
 mod cursor;
 mod iter;
-pub use cursor::{CursorMut, CursorRef};
-pub use iter::{IntoIter, Iter, IterMut};
+#[cfg(feature = "serde")]
+pub mod serde_chunk_size;
+pub use allocator::{Allocator, Global};
+pub use cursor::{CursorMut, CursorRef, GhostCursorMut};
+pub use iter::{Chunks, Cycle, IntoIter, Iter, IterMut, IterMutRev, IterRev, Windows};
 
 #[cfg(test)]
 extern crate rand;
@@ -83,7 +148,7 @@ extern crate rand;
 /// the heap, thus increasing CPU cache efficieny, as well as decreasing the number of
 /// allocations. It has the downside that you can't deallocate individual nodes, so the
 /// only way to deallocate memory owned by this list is to drop it.
-pub struct LinkedList<T> {
+pub struct LinkedList<T, A: Allocator = Global> {
     head: *mut LinkedNode<T>,
     tail: *mut LinkedNode<T>,
     len: usize,
@@ -91,18 +156,65 @@ pub struct LinkedList<T> {
     chunk_size: usize,
     allocations: Vec<(*mut LinkedNode<T>, usize)>,
     unused_nodes: *mut LinkedNode<T>,
+    alloc: A,
 }
 
 // LinkedLists own their data, so the borrow checker should prevent data races.
-unsafe impl<T: Send> Send for LinkedList<T> {}
-unsafe impl<T: Sync> Sync for LinkedList<T> {}
+unsafe impl<T: Send, A: Allocator + Send> Send for LinkedList<T, A> {}
+unsafe impl<T: Sync, A: Allocator + Sync> Sync for LinkedList<T, A> {}
 
+// The layout is fixed so that nodes can be reinterpreted by `map_in_place`.
+#[repr(C)]
 struct LinkedNode<T> {
     next: *mut LinkedNode<T>,
     prev: *mut LinkedNode<T>,
     value: T,
 }
 
+/// The layout of an allocation of `capacity` nodes. The size is checked by `allocate`.
+fn node_layout<T>(capacity: usize) -> Layout {
+    Layout::array::<LinkedNode<T>>(capacity).expect("capacity overflow")
+}
+
+/// Cleans up after `try_retain_map` if the closure panics.
+struct RetainMapGuard<'a, T: 'a, A: Allocator + 'a> {
+    list: &'a mut LinkedList<T, A>,
+    /// The retained nodes are linked from `head` to `last`, or both are null.
+    head: *mut LinkedNode<T>,
+    last: *mut LinkedNode<T>,
+    /// The node given to the closure, followed by the nodes that weren't processed yet.
+    rest: *mut LinkedNode<T>,
+}
+impl<'a, T, A: Allocator> Drop for RetainMapGuard<'a, T, A> {
+    fn drop(&mut self) {
+        unsafe {
+            if !self.last.is_null() {
+                let mut ptr = self.head;
+                loop {
+                    let next = (*ptr).next;
+                    let done = ptr == self.last;
+                    self.list.discard_node(ptr);
+                    if done {
+                        break;
+                    }
+                    ptr = next;
+                }
+            }
+            let mut ptr = self.rest;
+            while !ptr.is_null() {
+                let next = (*ptr).next;
+                self.list.discard_node(ptr);
+                ptr = next;
+            }
+        }
+    }
+}
+
+/// Deallocates a chunk of nodes allocated by `LinkedList::allocate`.
+unsafe fn deallocate_nodes<T, A: Allocator>(alloc: &A, base: *mut LinkedNode<T>, capacity: usize) {
+    allocator::deallocate(alloc, base as *mut u8, node_layout::<T>(capacity));
+}
+
 impl<T> LinkedList<T> {
     /// Creates an empty `LinkedList` with a chunk size of 64.
     ///
@@ -116,6 +228,129 @@ impl<T> LinkedList<T> {
     /// ```
     #[inline]
     pub fn new() -> LinkedList<T> {
+        LinkedList::new_in(Global)
+    }
+    /// Creates an empty `LinkedList` with a chunk size of 64 and makes a single
+    /// allocation with the specified amount of nodes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let list: LinkedList<u32> = LinkedList::with_capacity(293);
+    /// assert_eq!(293, list.capacity());
+    /// ```
+    #[inline]
+    pub fn with_capacity(cap: usize) -> LinkedList<T> {
+        LinkedList::with_capacity_and_chunk_size(cap, 64)
+    }
+    /// Creates an empty `LinkedList` with the specified chunk size. See
+    /// [`set_chunk_size`].
+    ///
+    /// # Panics
+    ///
+    /// This method panics if `chunk_size` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<u32> = LinkedList::with_chunk_size(16);
+    /// assert_eq!(16, list.chunk_size());
+    /// assert_eq!(0, list.capacity());
+    ///
+    /// list.push_back(1);
+    /// assert_eq!(16, list.capacity());
+    /// ```
+    ///
+    /// [`set_chunk_size`]: #method.set_chunk_size
+    #[inline]
+    pub fn with_chunk_size(chunk_size: usize) -> LinkedList<T> {
+        let mut list = LinkedList::new();
+        list.set_chunk_size(chunk_size);
+        list
+    }
+    /// Creates an empty `LinkedList` with the specified chunk size and makes a single
+    /// allocation with the specified amount of nodes.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if `chunk_size` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<u32> = LinkedList::with_capacity_and_chunk_size(2, 16);
+    /// assert_eq!(16, list.chunk_size());
+    /// assert_eq!(2, list.capacity());
+    ///
+    /// list.extend(&[1, 2]);
+    /// assert_eq!(2, list.capacity());
+    /// // the list is full, so this allocates another chunk
+    /// list.push_back(3);
+    /// assert_eq!(18, list.capacity());
+    /// ```
+    #[inline]
+    pub fn with_capacity_and_chunk_size(cap: usize, chunk_size: usize) -> LinkedList<T> {
+        LinkedList::with_capacity_and_chunk_size_in(cap, chunk_size, Global)
+    }
+    /// Creates a `LinkedList` with the specified chunk size containing the elements of
+    /// the iterator.
+    ///
+    /// Like `collect`, this makes a single allocation for the lower bound of the size
+    /// hint of the iterator, but any further allocations use the given chunk size
+    /// instead of the default of 64 nodes, which matters for iterators that can't
+    /// predict their length.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if `chunk_size` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// // filter doesn't know how many elements it yields
+    /// let list = LinkedList::from_iter_with_chunk_size((0..1000).filter(|i| i % 2 == 0), 500);
+    /// assert_eq!(500, list.len());
+    /// assert_eq!(500, list.chunk_size());
+    /// assert_eq!(1, list.number_of_allocations());
+    /// ```
+    pub fn from_iter_with_chunk_size<I: IntoIterator<Item = T>>(
+        iter: I,
+        chunk_size: usize,
+    ) -> LinkedList<T> {
+        let iter = iter.into_iter();
+        let mut list = LinkedList::with_chunk_size(chunk_size);
+        let cap = list.reserve_hint(iter.size_hint());
+        list.allocate(cap);
+        for item in iter {
+            list.push_back(item);
+        }
+        list
+    }
+}
+
+impl<T, A: Allocator> LinkedList<T, A> {
+    /// Creates an empty `LinkedList` with a chunk size of 64, which allocates its nodes
+    /// with the given allocator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #![cfg_attr(feature = "allocator_api", feature(allocator_api))]
+    /// use linked_list::{Global, LinkedList};
+    ///
+    /// let list: LinkedList<u32, Global> = LinkedList::new_in(Global);
+    /// assert_eq!(64, list.chunk_size());
+    /// ```
+    #[inline]
+    pub fn new_in(alloc: A) -> LinkedList<T, A> {
         LinkedList {
             head: ptr::null_mut(),
             tail: ptr::null_mut(),
@@ -124,33 +359,103 @@ impl<T> LinkedList<T> {
             chunk_size: 64,
             allocations: Vec::new(),
             unused_nodes: ptr::null_mut(),
+            alloc,
         }
     }
     /// Creates an empty `LinkedList` with a chunk size of 64 and makes a single
-    /// allocation with the specified amount of nodes.
+    /// allocation with the specified amount of nodes using the given allocator.
     ///
     /// # Examples
     ///
     /// ```
-    /// use linked_list::LinkedList;
+    /// # #![cfg_attr(feature = "allocator_api", feature(allocator_api))]
+    /// use linked_list::{Global, LinkedList};
     ///
-    /// let list: LinkedList<u32> = LinkedList::with_capacity(293);
+    /// let list: LinkedList<u32, Global> = LinkedList::with_capacity_in(293, Global);
     /// assert_eq!(293, list.capacity());
     /// ```
     #[inline]
-    pub fn with_capacity(cap: usize) -> LinkedList<T> {
+    pub fn with_capacity_in(cap: usize, alloc: A) -> LinkedList<T, A> {
+        LinkedList::with_capacity_and_chunk_size_in(cap, 64, alloc)
+    }
+    /// Creates an empty `LinkedList` with the specified chunk size, which allocates its
+    /// nodes with the given allocator. See [`set_chunk_size`].
+    ///
+    /// # Panics
+    ///
+    /// This method panics if `chunk_size` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #![cfg_attr(feature = "allocator_api", feature(allocator_api))]
+    /// use linked_list::{Global, LinkedList};
+    ///
+    /// let list: LinkedList<u32, Global> = LinkedList::with_chunk_size_in(16, Global);
+    /// assert_eq!(16, list.chunk_size());
+    /// assert_eq!(0, list.capacity());
+    /// ```
+    ///
+    /// [`set_chunk_size`]: #method.set_chunk_size
+    #[inline]
+    pub fn with_chunk_size_in(chunk_size: usize, alloc: A) -> LinkedList<T, A> {
+        let mut list = LinkedList::new_in(alloc);
+        list.set_chunk_size(chunk_size);
+        list
+    }
+    /// Creates an empty `LinkedList` with the specified chunk size and makes a single
+    /// allocation with the specified amount of nodes using the given allocator.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if `chunk_size` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #![cfg_attr(feature = "allocator_api", feature(allocator_api))]
+    /// use linked_list::{Global, LinkedList};
+    ///
+    /// let list: LinkedList<u32, Global> =
+    ///     LinkedList::with_capacity_and_chunk_size_in(2, 16, Global);
+    /// assert_eq!(16, list.chunk_size());
+    /// assert_eq!(2, list.capacity());
+    /// ```
+    #[inline]
+    pub fn with_capacity_and_chunk_size_in(
+        cap: usize,
+        chunk_size: usize,
+        alloc: A,
+    ) -> LinkedList<T, A> {
+        assert!(chunk_size > 0);
         let mut list = LinkedList {
             head: ptr::null_mut(),
             tail: ptr::null_mut(),
             len: 0,
             capacity: 0,
-            chunk_size: 64,
+            chunk_size,
             allocations: Vec::with_capacity(1),
             unused_nodes: ptr::null_mut(),
+            alloc,
         };
         list.allocate(cap);
         list
     }
+    /// Returns a reference to the allocator used by the list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #![cfg_attr(feature = "allocator_api", feature(allocator_api))]
+    /// use linked_list::{Global, LinkedList};
+    ///
+    /// let list: LinkedList<u32> = LinkedList::new();
+    /// let _: &Global = list.allocator();
+    /// ```
+    #[inline]
+    pub fn allocator(&self) -> &A {
+        &self.alloc
+    }
 
     /// Add the element to the back of the linked list in `O(1)`, unless it has to
     /// allocate, which is `O(chunk_size)`.
@@ -236,6 +541,38 @@ impl<T> LinkedList<T> {
         self.head = node;
         self.len += 1;
     }
+    /// Adds the elements of the iterator to the front of the list, such that they keep
+    /// the order of the iterator and come before the existing elements.
+    ///
+    /// The first element is pushed to the front, and the rest are inserted after it with
+    /// [`CursorMut::insert_many`], so the iterator isn't collected or reversed first.
+    /// Capacity is reserved up front using the size hint of
+    /// the iterator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<u32> = LinkedList::new();
+    /// list.extend(&[4, 5]);
+    ///
+    /// list.extend_front(vec![1, 2, 3]);
+    /// assert_eq!(list, vec![1, 2, 3, 4, 5]);
+    /// ```
+    ///
+    /// [`CursorMut::insert_many`]: struct.CursorMut.html#method.insert_many
+    pub fn extend_front<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let mut iter = iter.into_iter();
+        let additional = self.reserve_hint(iter.size_hint());
+        self.reserve(additional);
+
+        if let Some(first) = iter.next() {
+            self.push_front(first);
+            let head = self.head;
+            CursorMut::create(self, head, 0).insert_many(iter);
+        }
+    }
     /// Provides a reference to the back element, or `None` if the list is empty.
     ///
     /// # Examples
@@ -360,6 +697,112 @@ impl<T> LinkedList<T> {
             unsafe { Some(&mut (*self.head).value) }
         }
     }
+    /// Provides mutable references to the front and back elements at the same time.
+    ///
+    /// If the list has a single element, it is both the front and the back, so it is
+    /// only returned once, as the front. If the list is empty, both are `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<u32> = LinkedList::new();
+    /// list.extend(&[1, 2, 3]);
+    ///
+    /// if let (Some(front), Some(back)) = list.front_back_mut() {
+    ///     std::mem::swap(front, back);
+    /// }
+    /// # else { unreachable!(); }
+    /// assert_eq!(list, vec![3, 2, 1]);
+    ///
+    /// let mut single: LinkedList<u32> = LinkedList::new();
+    /// single.push_back(1);
+    /// assert_eq!((Some(&mut 1), None), single.front_back_mut());
+    /// ```
+    pub fn front_back_mut(&mut self) -> (Option<&mut T>, Option<&mut T>) {
+        match self.len {
+            0 => (None, None),
+            1 => (self.front_mut(), None),
+            _ => unsafe { (Some(&mut (*self.head).value), Some(&mut (*self.tail).value)) },
+        }
+    }
+    /// Provides a mutable reference to the front element together with a mutable
+    /// iterator over the rest of the list, or `None` if the list is empty. This is the
+    /// linked list version of [`slice::split_first_mut`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<u32> = LinkedList::new();
+    /// list.extend(&[1, 2, 3]);
+    ///
+    /// if let Some((first, rest)) = list.split_first_mut() {
+    ///     for value in rest {
+    ///         *value += *first;
+    ///     }
+    ///     *first = 0;
+    /// }
+    /// # else { unreachable!(); }
+    /// assert_eq!(list, vec![0, 3, 4]);
+    /// ```
+    ///
+    /// [`slice::split_first_mut`]: https://doc.rust-lang.org/std/primitive.slice.html#method.split_first_mut
+    pub fn split_first_mut(&mut self) -> Option<(&mut T, IterMut<'_, T>)> {
+        if self.head.is_null() {
+            return None;
+        }
+        unsafe {
+            // the iterator never reaches the first node, so the references don't alias
+            let rest = IterMut {
+                head: (*self.head).next,
+                tail: self.tail,
+                len: self.len - 1,
+                marker: PhantomData,
+            };
+            Some((&mut (*self.head).value, rest))
+        }
+    }
+    /// Provides a mutable reference to the back element together with a mutable iterator
+    /// over the rest of the list, or `None` if the list is empty. This is the linked list
+    /// version of [`slice::split_last_mut`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<u32> = LinkedList::new();
+    /// list.extend(&[1, 2, 3]);
+    ///
+    /// if let Some((last, rest)) = list.split_last_mut() {
+    ///     for value in rest {
+    ///         *last += *value;
+    ///         *value = 0;
+    ///     }
+    /// }
+    /// # else { unreachable!(); }
+    /// assert_eq!(list, vec![0, 0, 6]);
+    /// ```
+    ///
+    /// [`slice::split_last_mut`]: https://doc.rust-lang.org/std/primitive.slice.html#method.split_last_mut
+    pub fn split_last_mut(&mut self) -> Option<(&mut T, IterMut<'_, T>)> {
+        if self.tail.is_null() {
+            return None;
+        }
+        unsafe {
+            // the iterator never reaches the last node, so the references don't alias
+            let rest = IterMut {
+                head: self.head,
+                tail: (*self.tail).prev,
+                len: self.len - 1,
+                marker: PhantomData,
+            };
+            Some((&mut (*self.tail).value, rest))
+        }
+    }
     /// Removes the back element and returns it, or `None` if the list is empty.
     ///
     /// This is an `O(1)` operation.
@@ -403,6 +846,8 @@ impl<T> LinkedList<T> {
 
                 if self.head == tail {
                     self.head = ptr::null_mut();
+                } else {
+                    (*self.tail).next = ptr::null_mut();
                 }
 
                 self.len -= 1;
@@ -456,6 +901,8 @@ impl<T> LinkedList<T> {
 
                 if self.tail == head {
                     self.tail = ptr::null_mut();
+                } else {
+                    (*self.head).prev = ptr::null_mut();
                 }
 
                 self.len -= 1;
@@ -466,14 +913,14 @@ impl<T> LinkedList<T> {
             }
         }
     }
-
-    /// Retains only the elements specified by the predicate.
+    /// Removes the first `n` elements, or every element if the list is shorter, and
+    /// returns them in a new list in the same order.
     ///
-    /// In other words, remove all elements `e` such that `f(&e)` returns `false`. This
-    /// method operates in place and preserves the order of the retained elements.
+    /// The values are moved into a new list with room for exactly the removed elements
+    /// and the same chunk size. The nodes they occupied stay in this list as unused
+    /// capacity, see [node ownership].
     ///
-    /// If the closure or drop panics then the list is cleared without calling drop and some
-    /// capacity may be lost.
+    /// [node ownership]: index.html#node-ownership
     ///
     /// # Examples
     ///
@@ -481,26 +928,37 @@ impl<T> LinkedList<T> {
     /// use linked_list::LinkedList;
     ///
     /// let mut list: LinkedList<u32> = LinkedList::new();
-    /// list.extend(&[0,1,2,3,4,5,6,7,8,9,10]);
+    /// list.extend(&[1, 2, 3, 4, 5]);
+    /// let capacity = list.capacity();
     ///
-    /// // remove all odd values
-    /// list.retain(|&val| val % 2 == 0);
+    /// let batch = list.pop_front_n(2);
+    /// assert_eq!(batch, vec![1, 2]);
+    /// assert_eq!(list, vec![3, 4, 5]);
+    /// assert_eq!(capacity, list.capacity());
     ///
-    /// assert_eq!(list, vec![0,2,4,6,8,10]);
+    /// assert_eq!(list.pop_front_n(10), vec![3, 4, 5]);
+    /// assert!(list.is_empty());
     /// ```
-    pub fn retain(&mut self, mut f: impl FnMut(&T) -> bool) {
-        self.retain_map(|val| if f(&val) { Some(val) } else { None });
+    pub fn pop_front_n(&mut self, n: usize) -> LinkedList<T, A>
+    where
+        A: Clone,
+    {
+        let n = cmp::min(n, self.len);
+        let mut list =
+            LinkedList::with_capacity_and_chunk_size_in(n, self.chunk_size, self.alloc.clone());
+        for _ in 0..n {
+            list.push_back(self.pop_front().unwrap());
+        }
+        list
     }
-    /// Retains only the elements specified by the predicate.
+    /// Removes the last `n` elements, or every element if the list is shorter, and
+    /// returns them in a new list in the same order.
     ///
-    /// In other words, remove all elements `e` such that `f(&e)` returns `false`. This
-    /// method operates in place and preserves the order of the retained elements.
-    ///
-    /// Note that `retain_mut` lets you mutate every element in the list, regardless of
-    /// whether you choose to keep or remove it.
+    /// The values are moved into a new list with room for exactly the removed elements
+    /// and the same chunk size. The nodes they occupied stay in this list as unused
+    /// capacity, see [node ownership].
     ///
-    /// If the closure or drop panics then the list is cleared without calling drop and
-    /// some capacity may be lost.
+    /// [node ownership]: index.html#node-ownership
     ///
     /// # Examples
     ///
@@ -508,220 +966,118 @@ impl<T> LinkedList<T> {
     /// use linked_list::LinkedList;
     ///
     /// let mut list: LinkedList<u32> = LinkedList::new();
-    /// list.extend(&[0,1,2,3,4,5,6,7,8,9,10]);
-    ///
-    /// // add one to the value, then keep the odd values
-    /// list.retain_mut(|val| {
-    ///     *val += 1;
-    ///     *val % 2 == 1
-    /// });
+    /// list.extend(&[1, 2, 3, 4, 5]);
     ///
-    /// assert_eq!(list, vec![1,3,5,7,9,11]);
+    /// let batch = list.pop_back_n(2);
+    /// assert_eq!(batch, vec![4, 5]);
+    /// assert_eq!(list, vec![1, 2, 3]);
     /// ```
-    pub fn retain_mut(&mut self, mut f: impl FnMut(&mut T) -> bool) {
-        self.retain_map(|mut val| if f(&mut val) { Some(val) } else { None });
+    pub fn pop_back_n(&mut self, n: usize) -> LinkedList<T, A>
+    where
+        A: Clone,
+    {
+        let n = cmp::min(n, self.len);
+        let mut list =
+            LinkedList::with_capacity_and_chunk_size_in(n, self.chunk_size, self.alloc.clone());
+        for _ in 0..n {
+            list.push_front(self.pop_back().unwrap());
+        }
+        list
     }
-    /// Apply a mapping to the list in place, optionally removing elements.
-    ///
-    /// This method applies the closure to every element in the list, and replaces it with
-    /// the value returned by the closure, or removes it if the closure returned `None`.
-    /// This method preserves the order of the retained elements.
+    /// Removes the first `n` elements, or every element if the list is shorter, and
+    /// pushes them onto the back of `buf` in the same order. Returns the number of
+    /// elements moved.
     ///
-    /// Note that this method allows the closure to take ownership of removed elements.
+    /// Unlike [`pop_front_n`], this doesn't create a new list, so the caller can reuse
+    /// the same buffer for every batch. The nodes of the removed elements stay in this
+    /// list as unused capacity.
     ///
-    /// If the closure panics then the list is cleared without calling drop and some capacity may
-    /// be lost.
+    /// [`pop_front_n`]: #method.pop_front_n
     ///
     /// # Examples
     ///
     /// ```
     /// use linked_list::LinkedList;
     ///
-    /// // Create a list of owned strings.
-    /// let mut list: LinkedList<String> = LinkedList::new();
-    /// list.extend(vec!["first".to_string(), "second".to_string(), "third".to_string()]);
+    /// let mut list: LinkedList<u32> = (0..5).collect();
+    /// let capacity = list.capacity();
+    /// let mut buf = Vec::new();
     ///
-    /// // this example removes the middle element and makes the two other uppercase
-    /// let mut variable_outside_list = "not second".to_string();
+    /// assert_eq!(3, list.pop_front_into(&mut buf, 3));
+    /// assert_eq!(buf, [0, 1, 2]);
+    /// assert_eq!(list, vec![3, 4]);
     ///
-    /// list.retain_map(|string| {
-    ///     if string == "second" {
-    ///         // store the element outside the list and remove it
-    ///
-    ///         variable_outside_list = string;
-    ///         None
-    ///     } else {
-    ///         // replace the element with the uppercase version
-    ///
-    ///         Some(string.to_uppercase())
-    ///     }
-    /// });
-    ///
-    /// assert_eq!(list, vec!["FIRST", "THIRD"]);
-    /// assert_eq!(variable_outside_list, "second");
+    /// assert_eq!(2, list.pop_front_into(&mut buf, 3));
+    /// assert_eq!(buf, [0, 1, 2, 3, 4]);
+    /// assert!(list.is_empty());
+    /// assert_eq!(capacity, list.capacity());
     /// ```
-    pub fn retain_map(&mut self, mut f: impl FnMut(T) -> Option<T>) {
-        if self.is_empty() {
-            return;
-        }
-        let mut ptr = self.head;
-        let mut last_retain: *mut LinkedNode<T> = ptr::null_mut();
-        let capacity = self.capacity;
-
-        // If f panics, then we just throw away all the used nodes.
-        self.head = ptr::null_mut();
-        self.tail = ptr::null_mut();
-        self.len = 0;
-        // Since we are throwing away the used nodes, then the capacity is decreased by
-        // the number of used nodes.
-        self.capacity -= self.len;
-        // This means that if f panics, then we won't call drop on the remaining values,
-        // but that's safe so it's ok.
-        // We still deallocate the memory the nodes are stored in when the list is
-        // dropped, since we didn't touch the allocations array.
-
-        let mut new_head = ptr::null_mut();
-        let mut retained = 0;
-
-        unsafe {
-            while !ptr.is_null() {
-                let value_ptr = &mut (*ptr).value as *mut T;
-                let next_ptr = (*ptr).next;
-                match f(ptr::read(value_ptr)) {
-                    Some(new_value) => {
-                        ptr::write(value_ptr, new_value);
-                        if last_retain.is_null() {
-                            new_head = ptr;
-                        } else {
-                            (*last_retain).next = ptr;
-                        }
-                        (*ptr).prev = last_retain;
-                        last_retain = ptr;
-                        retained += 1;
-                    }
-                    None => {
-                        self.discard_node(ptr);
-                    }
-                }
-                ptr = next_ptr;
-            }
+    pub fn pop_front_into(&mut self, buf: &mut Vec<T>, n: usize) -> usize {
+        let n = cmp::min(n, self.len);
+        buf.reserve(n);
+        for _ in 0..n {
+            buf.push(self.pop_front().unwrap());
         }
-
-        self.head = new_head;
-        self.tail = last_retain;
-        self.len = retained;
-        // we didn't panic so put capacity back at the actual value
-        // we didn't allocate or deallocate in this method, so capacity is the same
-        self.capacity = capacity;
+        n
     }
-
-    /// Moves all elements from `other` to the back of the list.
+    /// Consumes the list, splitting it into `n` contiguous lists whose lengths differ by
+    /// at most one. The first `len % n` lists get the extra element, and if the list has
+    /// fewer than `n` elements, the last lists are empty. Appending the lists in order
+    /// gives back the original list.
     ///
-    /// This reuses all the nodes from `other` and moves them into `self`. After this
-    /// operation, `other` becomes empty.
-    /// Excess capacity as well as ownership of allocations in `other` is also moved into
-    /// `self`.
+    /// The first list is the original list, so it keeps the nodes of its elements as
+    /// well as all of the original allocations and capacity. The elements of the other
+    /// lists are moved into new lists with room for exactly their elements and the same
+    /// chunk size, see [node ownership].
     ///
-    /// This method guarantees that the capacity in `self` is increased by
-    /// `other.capacity()`, and that `other` will have a capacity of zero when this method
-    /// returns.
+    /// [node ownership]: index.html#node-ownership
     ///
-    /// Moving the nodes from `other` to `self` is `O(1)`, but moving the excess capacity
-    /// and the ownership of allocations requires a full iteration through one of them,
-    /// meaning it is linear time, although `append` will always iterate through the
-    /// shorter one.
+    /// # Panics
     ///
-    /// This method is `O(min(excess_capacity) + min(number_of_allocations))`.
+    /// Panics if `n` is zero.
     ///
     /// # Examples
     ///
     /// ```
     /// use linked_list::LinkedList;
     ///
-    /// let mut list_a: LinkedList<u32> = LinkedList::new();
-    /// let mut list_b: LinkedList<u32> = LinkedList::new();
-    ///
-    /// // add elements to both lists
-    /// list_a.extend(&[0,1,2,3,4]);
-    /// list_b.extend(&[5,6,7,8,9]);
-    ///
-    /// // remember their capacities before appending
-    /// let cap_a = list_a.capacity();
-    /// let cap_b = list_b.capacity();
-    ///
-    /// list_a.append(&mut list_b);
+    /// let list: LinkedList<u32> = (0..7).collect();
+    /// let capacity = list.capacity();
     ///
-    /// // check that the elements were moved
-    /// assert_eq!(list_a, vec![0,1,2,3,4,5,6,7,8,9]);
-    /// assert_eq!(list_b, vec![]);
+    /// let parts = list.split_into(3);
+    /// assert_eq!(parts[0], vec![0, 1, 2]);
+    /// assert_eq!(parts[1], vec![3, 4]);
+    /// assert_eq!(parts[2], vec![5, 6]);
     ///
-    /// // check that the capacity was moved
-    /// assert_eq!(cap_a + cap_b, list_a.capacity());
-    /// assert_eq!(0, list_b.capacity());
+    /// // the first part keeps the capacity of the original list
+    /// assert_eq!(capacity, parts[0].capacity());
+    /// assert_eq!(2, parts[1].capacity());
     /// ```
-    pub fn append(&mut self, other: &mut LinkedList<T>) {
-        if self.is_empty() {
-            // just directly move the chain to self
-            self.head = other.head;
-            self.tail = other.tail;
-            self.len = other.len;
-        } else if other.is_empty() {
-            // do nothing
-        } else {
-            // both have elements so we append the chain
-            unsafe {
-                (*self.tail).next = other.head;
-                (*other.head).prev = self.tail;
-                self.tail = other.tail;
-                self.len += other.len;
-            }
-        }
-
-        // move allocations
-        if self.allocations.len() < other.allocations.len() {
-            mem::swap(&mut self.allocations, &mut other.allocations);
-        }
-        // self.allocations is now the longest array
-        self.allocations.extend(other.allocations.drain(..));
-
-        // move unused capacity to self, since self now owns the memory
-        self.capacity += other.capacity;
-        self.combine_unused_nodes(other);
-
-        // other is now empty
-        other.head = ptr::null_mut();
-        other.tail = ptr::null_mut();
-        other.len = 0;
-        other.capacity = 0;
-        // allocations is emptied by drain
-        debug_assert!(other.allocations.is_empty());
-        // unused_nodes is moved by combined_unused_nodes
-        debug_assert!(other.unused_nodes.is_null());
-    }
-    fn combine_unused_nodes(&mut self, other: &mut LinkedList<T>) {
-        if self.capacity - self.len < other.capacity - other.len {
-            mem::swap(&mut self.unused_nodes, &mut other.unused_nodes);
-        }
-        // self.unused_nodes is now a longer linked list than the one in other
-        // let's find the last node in other.unused_nodes
-        let mut ptr = other.unused_nodes;
-        if ptr.is_null() {
-            // other is null, so we moved all unused_nodes with the swap
-            return;
-        }
-        unsafe {
-            // iterate to the last node
-            while !(*ptr).next.is_null() {
-                ptr = (*ptr).next;
-            }
-            // we now put the unused_nodes in other in front of the ones in self
-            (*ptr).next = self.unused_nodes;
-            self.unused_nodes = other.unused_nodes;
-            other.unused_nodes = ptr::null_mut();
+    pub fn split_into(mut self, n: usize) -> Vec<LinkedList<T, A>>
+    where
+        A: Clone,
+    {
+        assert!(n != 0, "cannot split a list into zero parts");
+        let size = self.len / n;
+        let extra = self.len % n;
+        let mut parts = Vec::with_capacity(n);
+        // take the parts from the back, so the first part is left in self
+        for i in (1..n).rev() {
+            let len = if i < extra { size + 1 } else { size };
+            parts.push(self.pop_back_n(len));
         }
+        parts.push(self);
+        parts.reverse();
+        parts
     }
 
-    /// Provides a forward iterator.
+    /// Retains only the elements specified by the predicate.
+    ///
+    /// In other words, remove all elements `e` such that `f(&e)` returns `false`. This
+    /// method operates in place and preserves the order of the retained elements.
+    ///
+    /// If the closure or drop panics then the list is cleared without calling drop on the
+    /// remaining elements, but no capacity is lost.
     ///
     /// # Examples
     ///
@@ -729,26 +1085,26 @@ impl<T> LinkedList<T> {
     /// use linked_list::LinkedList;
     ///
     /// let mut list: LinkedList<u32> = LinkedList::new();
-    /// list.push_back(0);
-    /// list.push_back(1);
-    /// list.push_back(2);
+    /// list.extend(&[0,1,2,3,4,5,6,7,8,9,10]);
     ///
-    /// let mut iter = list.iter();
-    /// assert_eq!(Some(&0), iter.next());
-    /// assert_eq!(Some(&1), iter.next());
-    /// assert_eq!(Some(&2), iter.next());
-    /// assert_eq!(None, iter.next());
+    /// // remove all odd values
+    /// list.retain(|&val| val % 2 == 0);
+    ///
+    /// assert_eq!(list, vec![0,2,4,6,8,10]);
     /// ```
-    #[inline]
-    pub fn iter(&self) -> Iter<T> {
-        Iter {
-            head: self.head,
-            tail: self.tail,
-            len: self.len,
-            marker: PhantomData,
-        }
+    pub fn retain(&mut self, mut f: impl FnMut(&T) -> bool) {
+        self.retain_map(|val| if f(&val) { Some(val) } else { None });
     }
-    /// Provides a forward iterator with mutable references.
+    /// Retains only the elements specified by the predicate.
+    ///
+    /// In other words, remove all elements `e` such that `f(&e)` returns `false`. This
+    /// method operates in place and preserves the order of the retained elements.
+    ///
+    /// Note that `retain_mut` lets you mutate every element in the list, regardless of
+    /// whether you choose to keep or remove it.
+    ///
+    /// If the closure or drop panics then the list is cleared without calling drop on the
+    /// remaining elements, but no capacity is lost.
     ///
     /// # Examples
     ///
@@ -756,60 +1112,67 @@ impl<T> LinkedList<T> {
     /// use linked_list::LinkedList;
     ///
     /// let mut list: LinkedList<u32> = LinkedList::new();
+    /// list.extend(&[0,1,2,3,4,5,6,7,8,9,10]);
     ///
-    /// list.push_back(0);
-    /// list.push_back(1);
-    /// list.push_back(2);
-    ///
-    /// for element in list.iter_mut() {
-    ///     *element += 10;
-    /// }
+    /// // add one to the value, then keep the odd values
+    /// list.retain_mut(|val| {
+    ///     *val += 1;
+    ///     *val % 2 == 1
+    /// });
     ///
-    /// let mut iter = list.iter();
-    /// assert_eq!(Some(&10), iter.next());
-    /// assert_eq!(Some(&11), iter.next());
-    /// assert_eq!(Some(&12), iter.next());
-    /// assert_eq!(None, iter.next());
+    /// assert_eq!(list, vec![1,3,5,7,9,11]);
     /// ```
-    #[inline]
-    pub fn iter_mut(&mut self) -> IterMut<T> {
-        IterMut {
-            head: self.head,
-            tail: self.tail,
-            len: self.len,
-            marker: PhantomData,
-        }
+    pub fn retain_mut(&mut self, mut f: impl FnMut(&mut T) -> bool) {
+        self.retain_map(|mut val| if f(&mut val) { Some(val) } else { None });
     }
-    /// Provides a cursor to the contents of the linked list, positioned at the back
-    /// element, or `None` if the list is empty.
+    /// Retains only the elements specified by the predicate, which is also given the
+    /// index of each element.
+    ///
+    /// The index is the position the element had in the list before any elements were
+    /// removed. This method operates in place and preserves the order of the retained
+    /// elements.
+    ///
+    /// If the closure or drop panics then the list is cleared without calling drop on the
+    /// remaining elements, but no capacity is lost.
     ///
     /// # Examples
     ///
     /// ```
     /// use linked_list::LinkedList;
     ///
-    /// let mut list: LinkedList<u32> = LinkedList::new();
-    /// assert!(list.cursor_ref_back().is_none());
-    /// list.push_back(5);
-    /// list.push_back(6);
+    /// let mut list: LinkedList<char> = LinkedList::new();
+    /// list.extend(&['a', 'b', 'c', 'd', 'e']);
     ///
-    /// if let Some(cursor) = list.cursor_ref_back() {
-    ///     assert_eq!(&6, cursor.get());
-    ///     assert_eq!(Some(&5), cursor.prev().map(|cursor| cursor.get()));
-    ///     assert!(cursor.next().is_none());
-    /// }
-    /// # else { unreachable!(); }
+    /// // remove the elements at even indexes
+    /// list.retain_indexed(|i, _| i % 2 == 1);
+    ///
+    /// assert_eq!(list, vec!['b', 'd']);
     /// ```
-    #[inline]
-    pub fn cursor_ref_back(&self) -> Option<CursorRef<T>> {
-        if self.tail.is_null() {
-            None
-        } else {
-            Some(CursorRef::create(self.tail, self.len - 1))
-        }
+    pub fn retain_indexed(&mut self, mut f: impl FnMut(usize, &T) -> bool) {
+        let mut index = 0;
+        self.retain_map(|val| {
+            let keep = f(index, &val);
+            index += 1;
+            if keep {
+                Some(val)
+            } else {
+                None
+            }
+        });
     }
-    /// Provides a cursor to the contents of the linked list, positioned at the front
-    /// element, or `None` if the list is empty.
+    /// Retains only the elements specified by the predicate, returning the removed
+    /// elements.
+    ///
+    /// In other words, remove all elements `e` such that `f(&e)` returns `false`, and
+    /// return them as a new list in their original order. This method operates in place
+    /// and preserves the order of the retained elements.
+    ///
+    /// The retained elements keep their nodes, and the nodes of the removed elements
+    /// become unused in this list, so no capacity is lost. The removed elements are moved
+    /// into a new list with the same chunk size.
+    ///
+    /// If the closure panics, the elements that were already removed are dropped along
+    /// with the new list, and the remaining elements are left in this list.
     ///
     /// # Examples
     ///
@@ -817,51 +1180,80 @@ impl<T> LinkedList<T> {
     /// use linked_list::LinkedList;
     ///
     /// let mut list: LinkedList<u32> = LinkedList::new();
-    /// assert!(list.cursor_ref_front().is_none());
-    /// list.push_front(5);
-    /// list.push_front(6);
+    /// list.extend(&[0,1,2,3,4,5,6,7,8,9,10]);
     ///
-    /// if let Some(cursor) = list.cursor_ref_front() {
-    ///     assert_eq!(&6, cursor.get());
-    ///     assert_eq!(Some(&5), cursor.next().map(|cursor| cursor.get()));
-    ///     assert!(cursor.prev().is_none());
-    /// }
-    /// # else { unreachable!(); }
+    /// // remove all odd values, keeping them in another list
+    /// let odd = list.retain_collect(|&val| val % 2 == 0);
+    ///
+    /// assert_eq!(list, vec![0,2,4,6,8,10]);
+    /// assert_eq!(odd, vec![1,3,5,7,9]);
     /// ```
-    #[inline]
-    pub fn cursor_ref_front(&self) -> Option<CursorRef<T>> {
-        if self.head.is_null() {
-            None
-        } else {
-            Some(CursorRef::create(self.head, 0))
-        }
-    }
-
-    pub fn cursor_mut_back(&mut self) -> Option<CursorMut<T>> {
-        if self.tail.is_null() {
-            None
-        } else {
-            let tail = self.tail;
-            let len = self.len;
-            Some(CursorMut::create(self, tail, len - 1))
-        }
-    }
-    pub fn cursor_mut_front(&mut self) -> Option<CursorMut<T>> {
-        if self.head.is_null() {
-            None
-        } else {
-            let head = self.head;
-            Some(CursorMut::create(self, head, 0))
+    pub fn retain_collect(&mut self, mut f: impl FnMut(&T) -> bool) -> LinkedList<T, A>
+    where
+        A: Clone,
+    {
+        let mut removed = LinkedList::with_chunk_size_in(self.chunk_size, self.alloc.clone());
+        let mut ptr = self.head;
+        while !ptr.is_null() {
+            unsafe {
+                let next = (*ptr).next;
+                if !f(&(*ptr).value) {
+                    removed.push_back(self.remove_node(ptr));
+                }
+                ptr = next;
+            }
         }
+        removed
     }
-
-    /// Removes all elements from the `LinkedList`. This method guarantees that capacity
-    /// is unchanged.
+    /// Apply a mapping to the list in place, optionally removing elements.
     ///
-    /// This is `O(self.len)` unless `T` has no destructor, in which case it's `O(1)`.
+    /// This method applies the closure to every element in the list, and replaces it with
+    /// the value returned by the closure, or removes it if the closure returned `None`.
+    /// This method preserves the order of the retained elements.
     ///
-    /// If drop on any element panics, this method won't drop the remaining nodes, but
-    /// the list will still be cleared and no capacity is lost.
+    /// Note that this method allows the closure to take ownership of removed elements.
+    ///
+    /// If the closure panics then the list is cleared without calling drop on the remaining
+    /// elements, but no capacity is lost.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// // Create a list of owned strings.
+    /// let mut list: LinkedList<String> = LinkedList::new();
+    /// list.extend(vec!["first".to_string(), "second".to_string(), "third".to_string()]);
+    ///
+    /// // this example removes the middle element and makes the two other uppercase
+    /// let mut variable_outside_list = "not second".to_string();
+    ///
+    /// list.retain_map(|string| {
+    ///     if string == "second" {
+    ///         // store the element outside the list and remove it
+    ///
+    ///         variable_outside_list = string;
+    ///         None
+    ///     } else {
+    ///         // replace the element with the uppercase version
+    ///
+    ///         Some(string.to_uppercase())
+    ///     }
+    /// });
+    ///
+    /// assert_eq!(list, vec!["FIRST", "THIRD"]);
+    /// assert_eq!(variable_outside_list, "second");
+    /// ```
+    pub fn retain_map(&mut self, mut f: impl FnMut(T) -> Option<T>) {
+        match self.try_retain_map::<Infallible>(|val| Ok(f(val))) {
+            Ok(()) => {}
+            Err(never) => match never {},
+        }
+    }
+    /// Apply a mapping to the list in place, optionally removing elements, and return the
+    /// number of removed elements.
+    ///
+    /// This works like [`retain_map`], and has the same behaviour if the closure panics.
     ///
     /// # Examples
     ///
@@ -869,65 +1261,173 @@ impl<T> LinkedList<T> {
     /// use linked_list::LinkedList;
     ///
     /// let mut list: LinkedList<u32> = LinkedList::new();
+    /// list.extend(&[1, 2, 3, 4, 5]);
     ///
-    /// list.push_front(2);
-    /// list.push_front(1);
-    /// assert_eq!(2, list.len());
-    /// assert_eq!(Some(&1), list.front());
+    /// // remove the even values and square the odd ones
+    /// let removed = list.retain_map_count(|val| if val % 2 == 0 { None } else { Some(val * val) });
     ///
-    /// let capacity_before_clear = list.capacity();
+    /// assert_eq!(2, removed);
+    /// assert_eq!(list, vec![1, 9, 25]);
+    /// ```
     ///
-    /// list.clear();
-    /// assert_eq!(0, list.len());
-    /// assert_eq!(None, list.front());
+    /// [`retain_map`]: #method.retain_map
+    pub fn retain_map_count(&mut self, f: impl FnMut(T) -> Option<T>) -> usize {
+        let len = self.len;
+        self.retain_map(f);
+        len - self.len
+    }
+    /// Apply a fallible mapping to the list in place, optionally removing elements.
+    ///
+    /// This works like [`retain_map`], except that the closure may fail. If the closure
+    /// returns an error, processing stops and the error is returned. Elements processed
+    /// before the error keep their new values, and elements after it are left untouched
+    /// in the list. The element passed to the failing call is consumed by the closure,
+    /// so if you want to keep it, you must return it inside the error.
+    ///
+    /// No capacity is lost when an error is returned.
+    ///
+    /// If the closure panics then the list is cleared without calling drop on the remaining
+    /// elements, but no capacity is lost.
+    ///
+    /// # Examples
     ///
-    /// // no allocation was lost
-    /// assert_eq!(capacity_before_clear, list.capacity());
     /// ```
-    pub fn clear(&mut self) {
-        if self.tail.is_null() {
-            return;
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<u32> = LinkedList::new();
+    /// list.extend(&[1, 2, 3, 4, 5]);
+    ///
+    /// // double the values and remove the even ones, but stop at 4
+    /// let result = list.try_retain_map(|val| {
+    ///     if val == 4 {
+    ///         Err(val)
+    ///     } else if val % 2 == 0 {
+    ///         Ok(None)
+    ///     } else {
+    ///         Ok(Some(val * 2))
+    ///     }
+    /// });
+    ///
+    /// assert_eq!(result, Err(4));
+    /// assert_eq!(list, vec![2, 6, 5]);
+    /// ```
+    ///
+    /// [`retain_map`]: #method.retain_map
+    pub fn try_retain_map<E>(
+        &mut self,
+        mut f: impl FnMut(T) -> Result<Option<T>, E>,
+    ) -> Result<(), E> {
+        if self.is_empty() {
+            return Ok(());
         }
-
+        let len = self.len;
         let tail = self.tail;
-
-        unsafe {
-            // just append unused_nodes to the linked list, and make the result into the
-            // new unused_nodes
-            (*self.tail).next = self.unused_nodes;
-            // unused_nodes is singly linked, so we don't need the other link
-            self.unused_nodes = self.head;
-        }
-        self.head = ptr::null_mut();
+        let head = mem::replace(&mut self.head, ptr::null_mut());
         self.tail = ptr::null_mut();
         self.len = 0;
 
-        if mem::needs_drop::<T>() {
-            let mut ptr = tail;
-            while !ptr.is_null() {
-                unsafe {
-                    ptr::drop_in_place(&mut (*ptr).value);
-                    ptr = (*ptr).prev;
+        // If f panics, the guard moves the nodes that are still in use to the unused
+        // nodes without dropping their values, so the list is left empty but no capacity
+        // is lost.
+        let mut guard = RetainMapGuard {
+            list: self,
+            head: ptr::null_mut(),
+            last: ptr::null_mut(),
+            rest: head,
+        };
+        let mut retained = 0;
+        let mut processed = 0;
+        let mut result = Ok(());
+
+        unsafe {
+            while !guard.rest.is_null() {
+                let ptr = guard.rest;
+                let value_ptr = &mut (*ptr).value as *mut T;
+                let next_ptr = (*ptr).next;
+                processed += 1;
+                match f(ptr::read(value_ptr)) {
+                    Ok(Some(new_value)) => {
+                        ptr::write(value_ptr, new_value);
+                        if guard.last.is_null() {
+                            guard.head = ptr;
+                        } else {
+                            (*guard.last).next = ptr;
+                        }
+                        (*ptr).prev = guard.last;
+                        guard.last = ptr;
+                        guard.rest = next_ptr;
+                        retained += 1;
+                    }
+                    Ok(None) => {
+                        guard.rest = next_ptr;
+                        guard.list.discard_node(ptr);
+                    }
+                    Err(err) => {
+                        guard.rest = next_ptr;
+                        guard.list.discard_node(ptr);
+                        result = Err(err);
+                        break;
+                    }
+                }
+            }
+
+            let rest = guard.rest;
+            let mut new_head = guard.head;
+            let last_retain = guard.last;
+            mem::forget(guard);
+
+            if rest.is_null() {
+                if !last_retain.is_null() {
+                    (*last_retain).next = ptr::null_mut();
+                }
+                self.tail = last_retain;
+            } else {
+                // we stopped early, so reattach the nodes we didn't process
+                if last_retain.is_null() {
+                    new_head = rest;
+                } else {
+                    (*last_retain).next = rest;
                 }
+                (*rest).prev = last_retain;
+                self.tail = tail;
+                retained += len - processed;
             }
+            self.head = new_head;
+            self.len = retained;
         }
+        result
     }
 
-    /// Returns the number of elements the list can hold without allocating.
+    /// Removes consecutive repeated elements in the list according to the
+    /// [`PartialEq`] trait implementation.
+    ///
+    /// The first element of every run of equal elements is kept. The nodes of the
+    /// removed elements are reused by later insertions, so the capacity is unchanged.
     ///
     /// # Examples
     ///
     /// ```
     /// use linked_list::LinkedList;
     ///
-    /// let mut list: LinkedList<u32> = LinkedList::with_capacity(48);
-    /// assert_eq!(48, list.capacity());
+    /// let mut list: LinkedList<u32> = LinkedList::new();
+    /// list.extend(&[1, 2, 2, 3, 2, 2, 2]);
+    /// let capacity = list.capacity();
+    ///
+    /// list.dedup();
+    ///
+    /// assert_eq!(list, vec![1, 2, 3, 2]);
+    /// assert_eq!(capacity, list.capacity());
     /// ```
-    #[inline]
-    pub fn capacity(&self) -> usize {
-        self.capacity
+    ///
+    /// [`PartialEq`]: https://doc.rust-lang.org/std/cmp/trait.PartialEq.html
+    pub fn dedup(&mut self)
+    where
+        T: PartialEq,
+    {
+        self.dedup_by(|a, b| a == b);
     }
-    /// Returns the number of items in the list.
+    /// Removes all but the first of consecutive elements in the list that resolve to the
+    /// same key.
     ///
     /// # Examples
     ///
@@ -935,21 +1435,61 @@ impl<T> LinkedList<T> {
     /// use linked_list::LinkedList;
     ///
     /// let mut list: LinkedList<u32> = LinkedList::new();
+    /// list.extend(&[10, 20, 21, 30, 20]);
     ///
-    /// list.push_front(2);
-    /// assert_eq!(1, list.len());
+    /// list.dedup_by_key(|i| *i / 10);
     ///
-    /// list.push_back(3);
-    /// assert_eq!(2, list.len());
+    /// assert_eq!(list, vec![10, 20, 30, 20]);
+    /// ```
+    pub fn dedup_by_key<K: PartialEq>(&mut self, mut key: impl FnMut(&mut T) -> K) {
+        self.dedup_by(|a, b| key(a) == key(b));
+    }
+    /// Removes all but the first of consecutive elements in the list satisfying a given
+    /// equality relation.
+    ///
+    /// The `same_bucket` function is passed references to two elements from the list,
+    /// and must determine if the elements compare equal. The elements are passed in
+    /// opposite order from their order in the list, so if `same_bucket(a, b)` returns
+    /// `true`, `a` is removed.
+    ///
+    /// # Examples
     ///
-    /// list.pop_front();
-    /// assert_eq!(1, list.len());
     /// ```
-    #[inline]
-    pub fn len(&self) -> usize {
-        self.len
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<&str> = LinkedList::new();
+    /// list.extend(&["foo", "bar", "Bar", "baz", "bar"]);
+    ///
+    /// list.dedup_by(|a, b| a.eq_ignore_ascii_case(b));
+    ///
+    /// assert_eq!(list, vec!["foo", "bar", "baz", "bar"]);
+    /// ```
+    pub fn dedup_by(&mut self, mut same_bucket: impl FnMut(&mut T, &mut T) -> bool) {
+        if self.len <= 1 {
+            return;
+        }
+        unsafe {
+            let mut kept = self.head;
+            let mut ptr = (*kept).next;
+            while !ptr.is_null() {
+                let next = (*ptr).next;
+                if same_bucket(&mut (*ptr).value, &mut (*kept).value) {
+                    // the list is valid before the value is dropped, in case drop panics
+                    drop(self.remove_node(ptr));
+                } else {
+                    kept = ptr;
+                }
+                ptr = next;
+            }
+        }
     }
-    /// Returns `true` if the list is empty.
+    /// Removes all but the first of consecutive repeated elements in the list like
+    /// [`dedup`], and returns the removed elements in a new list in the order they
+    /// appeared.
+    ///
+    /// The kept elements keep their nodes, and the nodes of the removed elements become
+    /// unused in this list, so no capacity is lost. The removed elements are moved into a
+    /// new list with the same chunk size.
     ///
     /// # Examples
     ///
@@ -957,27 +1497,84 @@ impl<T> LinkedList<T> {
     /// use linked_list::LinkedList;
     ///
     /// let mut list: LinkedList<u32> = LinkedList::new();
-    /// assert!(list.is_empty());
+    /// list.extend(&[1, 2, 2, 3, 2, 2, 2]);
+    /// let capacity = list.capacity();
     ///
-    /// list.push_back(3);
-    /// assert!(!list.is_empty());
+    /// let removed = list.dedup_collect();
     ///
-    /// list.pop_front();
-    /// assert!(list.is_empty());
+    /// assert_eq!(list, vec![1, 2, 3, 2]);
+    /// assert_eq!(removed, vec![2, 2, 2]);
+    /// assert_eq!(capacity, list.capacity());
     /// ```
-    #[inline]
-    pub fn is_empty(&self) -> bool {
-        self.len() == 0
+    ///
+    /// [`dedup`]: #method.dedup
+    pub fn dedup_collect(&mut self) -> LinkedList<T, A>
+    where
+        T: PartialEq,
+        A: Clone,
+    {
+        let mut removed = LinkedList::with_chunk_size_in(self.chunk_size, self.alloc.clone());
+        if self.len <= 1 {
+            return removed;
+        }
+        unsafe {
+            let mut kept = self.head;
+            let mut ptr = (*kept).next;
+            while !ptr.is_null() {
+                let next = (*ptr).next;
+                if (*ptr).value == (*kept).value {
+                    removed.push_back(self.remove_node(ptr));
+                } else {
+                    kept = ptr;
+                }
+                ptr = next;
+            }
+        }
+        removed
     }
-
-    /// Change the size of future allocations. This has no effect on previous allocations.
+    /// Returns the number of maximal runs of equal adjacent elements, which is the length
+    /// the list would have after calling [`dedup`]. This is useful for sizing a
+    /// destination before deduplicating.
     ///
-    /// When some operation increases the size of the linked list past the capacity, the
-    /// linked list will allocate at least `chunk_size` nodes in one allocation.
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<u32> = LinkedList::new();
+    /// list.extend(&[1, 2, 2, 3, 2, 2, 2]);
+    ///
+    /// assert_eq!(4, list.count_runs());
+    /// list.dedup();
+    /// assert_eq!(4, list.len());
+    /// ```
+    ///
+    /// [`dedup`]: #method.dedup
+    pub fn count_runs(&self) -> usize
+    where
+        T: PartialEq,
+    {
+        let mut iter = self.iter();
+        let mut prev = match iter.next() {
+            Some(prev) => prev,
+            None => return 0,
+        };
+        let mut runs = 1;
+        for value in iter {
+            if value != prev {
+                runs += 1;
+            }
+            prev = value;
+        }
+        runs
+    }
+
+    /// Swaps the elements at index `i` and `j`. The nodes are relinked, so the values
+    /// themselves are not moved in memory.
     ///
     /// # Panics
     ///
-    /// This method panics if `chunk_size` is zero.
+    /// Panics if `i` or `j` is out of bounds.
     ///
     /// # Examples
     ///
@@ -985,553 +1582,6312 @@ impl<T> LinkedList<T> {
     /// use linked_list::LinkedList;
     ///
     /// let mut list: LinkedList<u32> = LinkedList::new();
-    /// // default chunk size is 64
-    /// assert_eq!(64, list.chunk_size());
+    /// list.extend(&[1, 2, 3, 4, 5]);
     ///
-    /// list.set_chunk_size(3);
-    /// assert_eq!(3, list.chunk_size());
+    /// list.swap(0, 3);
+    /// assert_eq!(list, vec![4, 2, 3, 1, 5]);
     ///
-    /// // add an element, which allocates 3 nodes
-    /// list.push_back(4);
-    /// assert_eq!(3, list.capacity());
+    /// list.swap(4, 3);
+    /// assert_eq!(list, vec![4, 2, 3, 5, 1]);
     /// ```
-    #[inline]
-    pub fn set_chunk_size(&mut self, chunk_size: usize) {
-        assert!(chunk_size > 0);
-        self.chunk_size = chunk_size;
+    pub fn swap(&mut self, i: usize, j: usize) {
+        assert!(
+            i < self.len && j < self.len,
+            "swap indices (is {} and {}) should be < len (is {})",
+            i,
+            j,
+            self.len
+        );
+        let (i, j) = match i.cmp(&j) {
+            Ordering::Less => (i, j),
+            Ordering::Greater => (j, i),
+            Ordering::Equal => return,
+        };
+        let a = self.node_at(i);
+        let b = self.node_at(j);
+        unsafe {
+            if j == i + 1 {
+                self.swap_adjacent(a, b);
+                return;
+            }
+            let a_prev = (*a).prev;
+            let a_next = (*a).next;
+            let b_prev = (*b).prev;
+            let b_next = (*b).next;
+
+            // since a and b are not neighbours, a_next and b_prev are never null
+            if a_prev.is_null() {
+                self.head = b;
+            } else {
+                (*a_prev).next = b;
+            }
+            (*a_next).prev = b;
+            (*b_prev).next = a;
+            if b_next.is_null() {
+                self.tail = a;
+            } else {
+                (*b_next).prev = a;
+            }
+
+            (*b).prev = a_prev;
+            (*b).next = a_next;
+            (*a).prev = b_prev;
+            (*a).next = b_next;
+        }
     }
-    /// Returns the minimum size of future allocations.  See [`set_chunk_size`].
+    /// Rotates the list `n` places to the left, so the element at index `n` becomes the
+    /// front, like [`VecDeque::rotate_left`]. The nodes are relinked, so this is
+    /// `O(min(n, len - n))` and the values are not moved.
     ///
-    /// [`set_chunk_size`]: #method.set_chunk_size
-    #[inline]
-    pub fn chunk_size(&self) -> usize {
-        self.chunk_size
-    }
-
-    /// Reserves capacity for at least `additional` more elements to be inserted in the
-    /// list. This method will not reserve less than [`chunk_size`] nodes to avoid
-    /// frequent allocations.
+    /// # Panics
     ///
-    /// This is `O(allocation_size)`.
+    /// Panics if `n` is greater than the length of the list.
     ///
     /// # Examples
     ///
     /// ```
     /// use linked_list::LinkedList;
     ///
-    /// let mut list: LinkedList<u32> = LinkedList::with_capacity(5);
-    /// assert_eq!(5, list.capacity());
+    /// let mut list: LinkedList<u32> = (0..10).collect();
     ///
-    /// list.push_back(3);
-    /// list.reserve(84); // 84 is larger than the default chunk size
+    /// list.rotate_left(3);
+    /// assert_eq!(list, vec![3, 4, 5, 6, 7, 8, 9, 0, 1, 2]);
     ///
-    /// // there's already one element in the list, so it increases the capacity to 85
-    /// // the actual size of the allocation is 80, since the previous capacity was 5
-    /// assert_eq!(85, list.capacity());
+    /// list.rotate_left(10);
+    /// assert_eq!(list, vec![3, 4, 5, 6, 7, 8, 9, 0, 1, 2]);
     /// ```
     ///
-    /// [`chunk_size`]: #method.chunk_size
-    pub fn reserve(&mut self, additional: usize) {
-        let free_capacity = self.capacity() - self.len();
-        if free_capacity >= additional {
-            return;
-        }
-        let to_allocate = additional - free_capacity;
-
-        let chunk_size = self.chunk_size;
-        if to_allocate < chunk_size {
-            self.allocate(chunk_size);
-        } else {
-            self.allocate(to_allocate);
+    /// [`VecDeque::rotate_left`]: https://doc.rust-lang.org/std/collections/struct.VecDeque.html#method.rotate_left
+    pub fn rotate_left(&mut self, n: usize) {
+        assert!(
+            n <= self.len,
+            "rotate by (is {}) should be <= len (is {})",
+            n,
+            self.len
+        );
+        if let Some(cursor) = self.cursor_mut_at(n) {
+            cursor.rotate_to_front();
         }
     }
-    /// Reserves capacity for exactly `additional` more elements to be inserted in the
-    /// list.
+    /// Rotates the list `n` places to the right, so the element at index `len - n`
+    /// becomes the front, like [`VecDeque::rotate_right`]. The nodes are relinked, so
+    /// this is `O(min(n, len - n))` and the values are not moved.
     ///
-    /// This is `O(additional)`.
+    /// # Panics
+    ///
+    /// Panics if `n` is greater than the length of the list.
     ///
     /// # Examples
     ///
     /// ```
     /// use linked_list::LinkedList;
     ///
-    /// let mut list: LinkedList<u32> = LinkedList::with_capacity(5);
-    /// assert_eq!(5, list.capacity());
+    /// let mut list: LinkedList<u32> = (0..10).collect();
     ///
-    /// list.push_back(3);
-    /// list.reserve_exact(5);
+    /// list.rotate_right(3);
+    /// assert_eq!(list, vec![7, 8, 9, 0, 1, 2, 3, 4, 5, 6]);
     ///
-    /// // there's already one element in the list, so it increases the capacity to 6
-    /// // the actual size of the allocation is 1, since the previous capacity was 5
-    /// assert_eq!(6, list.capacity());
+    /// list.rotate_right(10);
+    /// assert_eq!(list, vec![7, 8, 9, 0, 1, 2, 3, 4, 5, 6]);
     /// ```
     ///
-    /// [`chunk_size`]: #method.chunk_size
-    pub fn reserve_exact(&mut self, additional: usize) {
-        let free_capacity = self.capacity() - self.len();
-        if free_capacity >= additional {
-            return;
-        }
-        let to_allocate = additional - free_capacity;
-        self.allocate(to_allocate);
+    /// [`VecDeque::rotate_right`]: https://doc.rust-lang.org/std/collections/struct.VecDeque.html#method.rotate_right
+    pub fn rotate_right(&mut self, n: usize) {
+        assert!(
+            n <= self.len,
+            "rotate by (is {}) should be <= len (is {})",
+            n,
+            self.len
+        );
+        self.rotate_left(self.len - n);
     }
-
-    fn discard_node(&mut self, node: *mut LinkedNode<T>) {
+    /// Returns mutable references to the elements at index `i` and `j` at the same time.
+    ///
+    /// Returns `None` if `i == j` or either index is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<u32> = LinkedList::new();
+    /// list.extend(&[1, 2, 3, 4]);
+    ///
+    /// if let Some((a, b)) = list.get_pair_mut(3, 1) {
+    ///     *a += *b;
+    ///     *b = 0;
+    /// }
+    /// # else { unreachable!(); }
+    /// assert_eq!(list, vec![1, 0, 3, 6]);
+    ///
+    /// assert!(list.get_pair_mut(2, 2).is_none());
+    /// assert!(list.get_pair_mut(0, 4).is_none());
+    /// ```
+    pub fn get_pair_mut(&mut self, i: usize, j: usize) -> Option<(&mut T, &mut T)> {
+        if i == j || i >= self.len || j >= self.len {
+            return None;
+        }
+        let a = self.node_at(i);
+        let b = self.node_at(j);
+        // the indices are distinct, so the nodes are distinct and the references don't
+        // alias, and they borrow self mutably, so nothing else can touch the nodes
+        unsafe { Some((&mut (*a).value, &mut (*b).value)) }
+    }
+    /// Moves the first element equal to `x` to the front of the list, returning `true`
+    /// if such an element was found. The node is relinked, so the value is not moved.
+    ///
+    /// This is `O(position)`, which makes it useful for most-recently-used lists.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<u32> = LinkedList::new();
+    /// list.extend(&[1, 2, 3, 2]);
+    ///
+    /// assert!(list.move_to_front(&2));
+    /// assert_eq!(list, vec![2, 1, 3, 2]);
+    ///
+    /// assert!(!list.move_to_front(&4));
+    /// assert_eq!(list, vec![2, 1, 3, 2]);
+    /// ```
+    pub fn move_to_front(&mut self, x: &T) -> bool
+    where
+        T: PartialEq,
+    {
+        let node = match self.find_node(x) {
+            Some(node) => node,
+            None => return false,
+        };
         unsafe {
-            (*node).next = self.unused_nodes;
+            if (*node).prev.is_null() {
+                // already the front
+                return true;
+            }
+            self.unlink_node(node);
+            (*node).prev = ptr::null_mut();
+            (*node).next = self.head;
+            (*self.head).prev = node;
+            self.head = node;
         }
-        self.unused_nodes = node;
+        true
     }
-    fn new_node(
-        &mut self,
-        next: *mut LinkedNode<T>,
-        prev: *mut LinkedNode<T>,
-        value: T,
-    ) -> *mut LinkedNode<T> {
+    /// Moves the first element equal to `x` to the back of the list, returning `true`
+    /// if such an element was found. The node is relinked, so the value is not moved.
+    ///
+    /// This is `O(position)`, since the element is searched for from the front.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<u32> = LinkedList::new();
+    /// list.extend(&[1, 2, 3, 2]);
+    ///
+    /// assert!(list.move_to_back(&2));
+    /// assert_eq!(list, vec![1, 3, 2, 2]);
+    ///
+    /// assert!(!list.move_to_back(&4));
+    /// ```
+    pub fn move_to_back(&mut self, x: &T) -> bool
+    where
+        T: PartialEq,
+    {
+        let node = match self.find_node(x) {
+            Some(node) => node,
+            None => return false,
+        };
         unsafe {
-            if self.unused_nodes.is_null() {
-                let chunk_size = self.chunk_size;
-                self.allocate(chunk_size);
+            if (*node).next.is_null() {
+                // already the back
+                return true;
             }
-            let node = self.unused_nodes;
-            self.unused_nodes = (*node).next;
+            self.unlink_node(node);
+            (*node).next = ptr::null_mut();
+            (*node).prev = self.tail;
+            (*self.tail).next = node;
+            self.tail = node;
+        }
+        true
+    }
+
+    /// Returns the index of the first element matching the predicate, or `None` if no
+    /// element matches. The search starts at the front and stops at the first match.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<u32> = LinkedList::new();
+    /// list.extend(&[1, 2, 3, 2, 1]);
+    ///
+    /// assert_eq!(Some(1), list.position(|&i| i == 2));
+    /// assert_eq!(None, list.position(|&i| i == 4));
+    /// ```
+    pub fn position(&self, f: impl FnMut(&T) -> bool) -> Option<usize> {
+        self.iter().position(f)
+    }
+    /// Returns the index of the last element matching the predicate, or `None` if no
+    /// element matches. The search starts at the back and stops at the first match. The
+    /// index is counted from the front of the list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<u32> = LinkedList::new();
+    /// list.extend(&[1, 2, 3, 2, 1]);
+    ///
+    /// assert_eq!(Some(3), list.rposition(|&i| i == 2));
+    /// assert_eq!(None, list.rposition(|&i| i == 4));
+    /// ```
+    pub fn rposition(&self, f: impl FnMut(&T) -> bool) -> Option<usize> {
+        self.iter().rposition(f)
+    }
+    /// Inserts the value into this sorted list, keeping it sorted, and returns the index
+    /// of the new element. If the list contains elements equal to the value, it is
+    /// inserted after them.
+    ///
+    /// This walks from the front of the list to the insertion point, so it is `O(n)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<u32> = LinkedList::new();
+    /// for &i in &[3, 1, 2, 5, 4] {
+    ///     list.insert_sorted(i);
+    /// }
+    /// assert_eq!(list, vec![1, 2, 3, 4, 5]);
+    ///
+    /// assert_eq!(3, list.insert_sorted(3));
+    /// assert_eq!(list, vec![1, 2, 3, 3, 4, 5]);
+    /// ```
+    pub fn insert_sorted(&mut self, value: T) -> usize
+    where
+        T: Ord,
+    {
+        self.insert_sorted_by(value, |a, b| a.cmp(b))
+    }
+    /// Inserts the value into a list sorted by the given comparator function, keeping it
+    /// sorted, and returns the index of the new element. If the list contains elements
+    /// that compare equal to the value, it is inserted after them.
+    ///
+    /// The comparator is called with an element of the list as the first argument and
+    /// the new value as the second. This walks from the front of the list to the
+    /// insertion point, so it is `O(n)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<(u32, char)> = LinkedList::new();
+    /// list.insert_sorted_by((2, 'a'), |a, b| a.0.cmp(&b.0));
+    /// list.insert_sorted_by((1, 'b'), |a, b| a.0.cmp(&b.0));
+    /// list.insert_sorted_by((2, 'c'), |a, b| a.0.cmp(&b.0));
+    ///
+    /// assert_eq!(list, vec![(1, 'b'), (2, 'a'), (2, 'c')]);
+    /// ```
+    pub fn insert_sorted_by<F>(&mut self, value: T, mut compare: F) -> usize
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        let mut ptr = self.head;
+        let mut index = 0;
+        unsafe {
+            while !ptr.is_null() && compare(&(*ptr).value, &value) != Ordering::Greater {
+                ptr = (*ptr).next;
+                index += 1;
+            }
+            if ptr.is_null() {
+                self.push_back(value);
+            } else {
+                let prev = (*ptr).prev;
+                let node = self.new_node(ptr, prev, value);
+                (*ptr).prev = node;
+                if prev.is_null() {
+                    self.head = node;
+                } else {
+                    (*prev).next = node;
+                }
+                self.len += 1;
+            }
+        }
+        index
+    }
+    /// Returns a reference to the maximum element, or `None` if the list is empty. If
+    /// several elements are equally maximum, the last one is returned.
+    ///
+    /// This is named `max_element` rather than `max`, since `LinkedList` implements
+    /// `Ord`, and `list.max()` would call `Ord::max` instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<u32> = LinkedList::new();
+    /// assert_eq!(None, list.max_element());
+    ///
+    /// list.extend(&[3, 9, 2]);
+    /// assert_eq!(Some(&9), list.max_element());
+    /// ```
+    pub fn max_element(&self) -> Option<&T>
+    where
+        T: Ord,
+    {
+        self.iter().max()
+    }
+    /// Returns a reference to the minimum element, or `None` if the list is empty. If
+    /// several elements are equally minimum, the first one is returned.
+    ///
+    /// This is named `min_element` rather than `min`, since `LinkedList` implements
+    /// `Ord`, and `list.min()` would call `Ord::min` instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<u32> = LinkedList::new();
+    /// assert_eq!(None, list.min_element());
+    ///
+    /// list.extend(&[3, 9, 2]);
+    /// assert_eq!(Some(&2), list.min_element());
+    /// ```
+    pub fn min_element(&self) -> Option<&T>
+    where
+        T: Ord,
+    {
+        self.iter().min()
+    }
+    /// Returns a reference to the element that gives the maximum value from the
+    /// function, or `None` if the list is empty. If several elements are equally
+    /// maximum, the last one is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<&str> = LinkedList::new();
+    /// list.extend(&["apple", "fig", "banana", "kiwi"]);
+    ///
+    /// assert_eq!(Some(&"banana"), list.max_by_key(|s| s.len()));
+    /// ```
+    pub fn max_by_key<B: Ord, F: FnMut(&T) -> B>(&self, mut f: F) -> Option<&T> {
+        self.iter().max_by_key(|value| f(value))
+    }
+    /// Returns a reference to the element that gives the minimum value from the
+    /// function, or `None` if the list is empty. If several elements are equally
+    /// minimum, the first one is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<&str> = LinkedList::new();
+    /// list.extend(&["apple", "fig", "banana", "kiwi"]);
+    ///
+    /// assert_eq!(Some(&"fig"), list.min_by_key(|s| s.len()));
+    /// ```
+    pub fn min_by_key<B: Ord, F: FnMut(&T) -> B>(&self, mut f: F) -> Option<&T> {
+        self.iter().min_by_key(|value| f(value))
+    }
+    /// Returns `true` if the elements of the list are sorted in ascending order, meaning
+    /// that every element is less than or equal to the next one.
+    ///
+    /// Elements that can't be compared, such as `NaN`, make the list unsorted. The list
+    /// is scanned once from the front, stopping at the first pair out of order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<f64> = LinkedList::new();
+    /// assert!(list.is_sorted());
+    ///
+    /// list.extend(&[1.0, 2.0, 2.0, 9.0]);
+    /// assert!(list.is_sorted());
+    ///
+    /// list.push_back(3.0);
+    /// assert!(!list.is_sorted());
+    /// ```
+    pub fn is_sorted(&self) -> bool
+    where
+        T: PartialOrd,
+    {
+        self.is_sorted_by(|a, b| a <= b)
+    }
+    /// Returns `true` if the elements of the list are sorted according to the given
+    /// function, meaning that it returns `true` for every pair of neighbouring elements.
+    ///
+    /// The list is scanned once from the front, stopping at the first pair for which the
+    /// function returns `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<u32> = LinkedList::new();
+    /// list.extend(&[9, 5, 5, 1]);
+    ///
+    /// assert!(list.is_sorted_by(|a, b| a >= b));
+    /// assert!(!list.is_sorted_by(|a, b| a > b));
+    /// ```
+    pub fn is_sorted_by<F>(&self, mut compare: F) -> bool
+    where
+        F: FnMut(&T, &T) -> bool,
+    {
+        let mut iter = self.iter();
+        let mut prev = match iter.next() {
+            Some(prev) => prev,
+            None => return true,
+        };
+        for value in iter {
+            if !compare(prev, value) {
+                return false;
+            }
+            prev = value;
+        }
+        true
+    }
+    /// Binary searches this sorted list for the given element.
+    ///
+    /// If the value is found then `Ok` is returned, containing the index of the matching
+    /// element. If there are multiple matches, then any one of the matches could be
+    /// returned. If the value is not found then `Err` is returned, containing the index
+    /// where a matching element could be inserted while maintaining sorted order.
+    ///
+    /// The search moves a cursor from one probe to the next, so the walks halve in length
+    /// each time and the total walk is `O(n)`, while only `O(log n)` comparisons are
+    /// made. This is mostly useful when comparisons are expensive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<u32> = LinkedList::new();
+    /// list.extend(&[1, 2, 3, 5, 8, 13]);
+    ///
+    /// assert_eq!(Ok(3), list.binary_search(&5));
+    /// assert_eq!(Err(4), list.binary_search(&6));
+    /// assert_eq!(Err(0), list.binary_search(&0));
+    /// assert_eq!(Err(6), list.binary_search(&100));
+    /// ```
+    pub fn binary_search(&self, x: &T) -> Result<usize, usize>
+    where
+        T: Ord,
+    {
+        self.binary_search_by(|item| item.cmp(x))
+    }
+    /// Binary searches this sorted list with a comparator function.
+    ///
+    /// The comparator function should return whether the element is `Less`, `Equal` or
+    /// `Greater` than the target, and the list must be sorted consistently with it. See
+    /// [`binary_search`] for the meaning of the return value and the cost of the search.
+    ///
+    /// [`binary_search`]: #method.binary_search
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<(u32, &str)> = LinkedList::new();
+    /// list.extend(vec![(1, "a"), (3, "b"), (4, "c")]);
+    ///
+    /// assert_eq!(Ok(1), list.binary_search_by(|&(key, _)| key.cmp(&3)));
+    /// assert_eq!(Err(1), list.binary_search_by(|&(key, _)| key.cmp(&2)));
+    /// ```
+    pub fn binary_search_by<F>(&self, mut f: F) -> Result<usize, usize>
+    where
+        F: FnMut(&T) -> Ordering,
+    {
+        let mut cursor = match self.cursor_ref_front() {
+            Some(cursor) => cursor,
+            None => return Err(0),
+        };
+        let mut low = 0;
+        let mut high = self.len;
+        while low < high {
+            let mid = low + (high - low) / 2;
+            cursor = if mid >= cursor.index() {
+                cursor.seek_forward(mid - cursor.index())
+            } else {
+                cursor.seek_backward(cursor.index() - mid)
+            }
+            .unwrap();
+            match f(cursor.get()) {
+                Ordering::Less => low = mid + 1,
+                Ordering::Greater => high = mid,
+                Ordering::Equal => return Ok(mid),
+            }
+        }
+        Err(low)
+    }
+
+    /// Removes the element at `index` and returns it, replacing it with the front
+    /// element.
+    ///
+    /// This does not preserve ordering, but it avoids unlinking a node in the middle of
+    /// the list. Reaching the element is `O(min(index, len - index))`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<u32> = LinkedList::new();
+    /// list.extend(&[1, 2, 3, 4]);
+    ///
+    /// assert_eq!(3, list.swap_remove_front(2));
+    /// assert_eq!(list, vec![2, 1, 4]);
+    /// ```
+    pub fn swap_remove_front(&mut self, index: usize) -> T {
+        assert!(
+            index < self.len,
+            "swap_remove_front index (is {}) should be < len (is {})",
+            index,
+            self.len
+        );
+        let node = self.node_at(index);
+        if node != self.head {
+            unsafe {
+                ptr::swap(&mut (*node).value, &mut (*self.head).value);
+            }
+        }
+        self.pop_front().unwrap()
+    }
+    /// Removes the element at `index` and returns it, replacing it with the back
+    /// element.
+    ///
+    /// This does not preserve ordering, but it avoids unlinking a node in the middle of
+    /// the list. Reaching the element is `O(min(index, len - index))`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<u32> = LinkedList::new();
+    /// list.extend(&[1, 2, 3, 4]);
+    ///
+    /// assert_eq!(2, list.swap_remove_back(1));
+    /// assert_eq!(list, vec![1, 4, 3]);
+    /// ```
+    pub fn swap_remove_back(&mut self, index: usize) -> T {
+        assert!(
+            index < self.len,
+            "swap_remove_back index (is {}) should be < len (is {})",
+            index,
+            self.len
+        );
+        let node = self.node_at(index);
+        if node != self.tail {
+            unsafe {
+                ptr::swap(&mut (*node).value, &mut (*self.tail).value);
+            }
+        }
+        self.pop_back().unwrap()
+    }
+
+    /// Consumes the list, creating two lists: one with the elements for which `f`
+    /// returned `true`, and one with the elements for which it returned `false`. The
+    /// order of the elements is preserved in both lists.
+    ///
+    /// Unlike `iter().cloned().partition()`, this moves the values instead of cloning
+    /// them. The first list is the original list, so it keeps every node of the elements
+    /// that matched as well as all of the original allocations and capacity. The
+    /// elements that didn't match are moved into a new list with the same chunk size,
+    /// see [node ownership].
+    ///
+    /// If the closure panics, the elements that were already moved to the second list
+    /// are dropped along with it.
+    ///
+    /// [node ownership]: index.html#node-ownership
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<u32> = LinkedList::new();
+    /// list.extend(&[1, 2, 3, 4, 5, 6]);
+    /// let capacity = list.capacity();
+    ///
+    /// let (even, odd) = list.partition(|&i| i % 2 == 0);
+    /// assert_eq!(even, vec![2, 4, 6]);
+    /// assert_eq!(odd, vec![1, 3, 5]);
+    ///
+    /// // the nodes of the odd values are unused in the first list
+    /// assert_eq!(capacity, even.capacity());
+    /// ```
+    pub fn partition<F: FnMut(&T) -> bool>(
+        mut self,
+        mut f: F,
+    ) -> (LinkedList<T, A>, LinkedList<T, A>)
+    where
+        A: Clone,
+    {
+        let mut rejected = LinkedList::with_chunk_size_in(self.chunk_size, self.alloc.clone());
+        let mut ptr = self.head;
+        while !ptr.is_null() {
+            unsafe {
+                let next = (*ptr).next;
+                if !f(&(*ptr).value) {
+                    rejected.push_back(self.remove_node(ptr));
+                }
+                ptr = next;
+            }
+        }
+        (self, rejected)
+    }
+
+    /// Apply a mapping to every element in the list, producing a list of a different
+    /// type.
+    ///
+    /// If `T` and `U` have the same size and alignment, the values are replaced in place
+    /// and the nodes and allocations of this list are reused by the new list, so no
+    /// allocation happens. Otherwise the values are moved into a newly allocated list
+    /// with the same chunk size.
+    ///
+    /// If the closure panics then the elements in the list are leaked, but the memory
+    /// used by the list is still deallocated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<u32> = LinkedList::new();
+    /// list.extend(&[1, 2, 3]);
+    /// let capacity = list.capacity();
+    ///
+    /// // u32 and i32 have the same layout, so this reuses the nodes
+    /// let negated: LinkedList<i32> = list.map_in_place(|i| -(i as i32));
+    /// assert_eq!(negated, vec![-1, -2, -3]);
+    /// assert_eq!(capacity, negated.capacity());
+    ///
+    /// // String is larger than i32, so this allocates a new list
+    /// let strings: LinkedList<String> = negated.map_in_place(|i| i.to_string());
+    /// assert_eq!(strings, vec!["-1", "-2", "-3"]);
+    /// ```
+    pub fn map_in_place<U, F: FnMut(T) -> U>(mut self, mut f: F) -> LinkedList<U, A>
+    where
+        A: Clone,
+    {
+        if mem::size_of::<T>() != mem::size_of::<U>()
+            || mem::align_of::<T>() != mem::align_of::<U>()
+        {
+            let mut list = LinkedList::with_capacity_and_chunk_size_in(
+                self.len,
+                self.chunk_size,
+                self.alloc.clone(),
+            );
+            for value in self {
+                list.push_back(f(value));
+            }
+            return list;
+        }
+
+        let head = self.head;
+        let tail = self.tail;
+        let len = self.len;
+
+        // If f panics, then self is dropped as an empty list, which deallocates the
+        // memory without dropping any of the values.
+        self.head = ptr::null_mut();
+        self.tail = ptr::null_mut();
+        self.len = 0;
+
+        // Since LinkedNode is repr(C) and T and U have the same size and alignment, a
+        // LinkedNode<T> has the same layout as a LinkedNode<U>.
+        let mut ptr = head;
+        unsafe {
+            while !ptr.is_null() {
+                let next = (*ptr).next;
+                let value = ptr::read(&(*ptr).value);
+                ptr::write(&mut (*(ptr as *mut LinkedNode<U>)).value, f(value));
+                ptr = next;
+            }
+        }
+
+        let allocations = mem::take(&mut self.allocations)
+            .into_iter()
+            .map(|(ptr, capacity)| (ptr as *mut LinkedNode<U>, capacity))
+            .collect();
+        let list = LinkedList {
+            head: head as *mut LinkedNode<U>,
+            tail: tail as *mut LinkedNode<U>,
+            len,
+            capacity: self.capacity,
+            chunk_size: self.chunk_size,
+            allocations,
+            unused_nodes: self.unused_nodes as *mut LinkedNode<U>,
+            alloc: self.alloc.clone(),
+        };
+
+        // self no longer owns any memory
+        self.capacity = 0;
+        self.unused_nodes = ptr::null_mut();
+        list
+    }
+
+    /// Moves all elements from `other` to the back of the list, like [`append`], but for
+    /// lists with any allocator.
+    ///
+    /// [`append`]: #method.append
+    ///
+    /// # Safety
+    ///
+    /// The allocations of `other` are moved to `self`, so the allocator of `self` must be
+    /// able to deallocate memory allocated by the allocator of `other`, for example
+    /// because one is a clone of the other.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #![cfg_attr(feature = "allocator_api", feature(allocator_api))]
+    /// use linked_list::{Global, LinkedList};
+    ///
+    /// let mut list_a: LinkedList<u32, Global> = LinkedList::new_in(Global);
+    /// let mut list_b: LinkedList<u32, Global> = LinkedList::new_in(Global);
+    /// list_a.extend(&[0, 1]);
+    /// list_b.extend(&[2, 3]);
+    ///
+    /// // both lists use the global allocator
+    /// unsafe { list_a.append_unchecked(&mut list_b) };
+    /// assert_eq!(list_a, vec![0, 1, 2, 3]);
+    /// assert!(list_b.is_empty());
+    /// ```
+    pub unsafe fn append_unchecked(&mut self, other: &mut LinkedList<T, A>) {
+        // self will own the memory, so this must happen before len is updated
+        self.take_memory(other);
+
+        if self.is_empty() {
+            // just directly move the chain to self
+            self.head = other.head;
+            self.tail = other.tail;
+            self.len = other.len;
+        } else if other.is_empty() {
+            // do nothing
+        } else {
+            // both have elements so we append the chain
+            unsafe {
+                (*self.tail).next = other.head;
+                (*other.head).prev = self.tail;
+                self.tail = other.tail;
+                self.len += other.len;
+            }
+        }
+
+        // other is now empty
+        other.head = ptr::null_mut();
+        other.tail = ptr::null_mut();
+        other.len = 0;
+    }
+    /// Moves all elements of `other` to the back of this list, leaving `other` empty but
+    /// keeping its capacity and allocations, so it can be refilled without allocating.
+    ///
+    /// Unlike [`append`], the values are moved into new nodes in this list rather than
+    /// moving the allocations of `other`, see [node ownership]. This is
+    /// `O(other.len())`, and the nodes they occupied become unused capacity in `other`.
+    ///
+    /// [`append`]: #method.append
+    /// [node ownership]: index.html#node-ownership
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list_a: LinkedList<u32> = LinkedList::new();
+    /// let mut list_b: LinkedList<u32> = LinkedList::with_chunk_size(8);
+    /// list_a.extend(&[0, 1]);
+    /// list_b.extend(&[2, 3, 4]);
+    /// let cap_b = list_b.capacity();
+    ///
+    /// list_a.append_elements_only(&mut list_b);
+    /// assert_eq!(list_a, vec![0, 1, 2, 3, 4]);
+    /// assert!(list_b.is_empty());
+    /// assert_eq!(cap_b, list_b.capacity());
+    /// assert_eq!(8, list_b.chunk_size());
+    /// ```
+    pub fn append_elements_only<B: Allocator>(&mut self, other: &mut LinkedList<T, B>) {
+        self.reserve(other.len);
+        while let Some(value) = other.pop_front() {
+            self.push_back(value);
+        }
+    }
+}
+
+impl<T> LinkedList<T> {
+    /// Moves all elements from `other` to the back of the list.
+    ///
+    /// This reuses all the nodes from `other` and moves them into `self`. After this
+    /// operation, `other` becomes empty.
+    /// Excess capacity as well as ownership of allocations in `other` is also moved into
+    /// `self`.
+    ///
+    /// This method guarantees that the capacity in `self` is increased by
+    /// `other.capacity()`, and that `other` will have a capacity of zero when this method
+    /// returns.
+    ///
+    /// Moving the nodes from `other` to `self` is `O(1)`, but moving the excess capacity
+    /// and the ownership of allocations requires a full iteration through one of them,
+    /// meaning it is linear time, although `append` will always iterate through the
+    /// shorter one.
+    ///
+    /// This method is `O(min(excess_capacity) + min(number_of_allocations))`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list_a: LinkedList<u32> = LinkedList::new();
+    /// let mut list_b: LinkedList<u32> = LinkedList::new();
+    ///
+    /// // add elements to both lists
+    /// list_a.extend(&[0,1,2,3,4]);
+    /// list_b.extend(&[5,6,7,8,9]);
+    ///
+    /// // remember their capacities before appending
+    /// let cap_a = list_a.capacity();
+    /// let cap_b = list_b.capacity();
+    ///
+    /// list_a.append(&mut list_b);
+    ///
+    /// // check that the elements were moved
+    /// assert_eq!(list_a, vec![0,1,2,3,4,5,6,7,8,9]);
+    /// assert_eq!(list_b, vec![]);
+    ///
+    /// // check that the capacity was moved
+    /// assert_eq!(cap_a + cap_b, list_a.capacity());
+    /// assert_eq!(0, list_b.capacity());
+    /// ```
+    pub fn append(&mut self, other: &mut LinkedList<T>) {
+        // the global allocator can free memory allocated for any list
+        unsafe { self.append_unchecked(other) };
+    }
+    /// Moves the first `n` elements of `other`, or all of them if `other` is shorter, to
+    /// the back of this list.
+    ///
+    /// If every element of `other` is moved, this is the same as [`append`], so the
+    /// nodes are linked into this list and the capacity and allocations of `other` are
+    /// moved as well. Otherwise the values are moved into new nodes in this list, and the
+    /// nodes they occupied stay in `other` as unused capacity, see [node ownership].
+    /// This is `O(n)`.
+    ///
+    /// [`append`]: #method.append
+    /// [node ownership]: index.html#node-ownership
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list_a: LinkedList<u32> = LinkedList::new();
+    /// let mut list_b: LinkedList<u32> = LinkedList::new();
+    /// list_a.extend(&[0, 1]);
+    /// list_b.extend(&[2, 3, 4, 5]);
+    /// let cap_b = list_b.capacity();
+    ///
+    /// list_a.append_n(&mut list_b, 2);
+    /// assert_eq!(list_a, vec![0, 1, 2, 3]);
+    /// assert_eq!(list_b, vec![4, 5]);
+    /// assert_eq!(cap_b, list_b.capacity());
+    ///
+    /// list_a.append_n(&mut list_b, 10);
+    /// assert_eq!(list_a, vec![0, 1, 2, 3, 4, 5]);
+    /// assert!(list_b.is_empty());
+    /// assert_eq!(0, list_b.capacity());
+    /// ```
+    pub fn append_n(&mut self, other: &mut LinkedList<T>, n: usize) {
+        if n >= other.len {
+            self.append(other);
+            return;
+        }
+        self.reserve(n);
+        for _ in 0..n {
+            self.push_back(other.pop_front().unwrap());
+        }
+    }
+    /// Moves all elements of `other` into this list, alternating between the elements of
+    /// the two lists starting with this one, so the result is `[s0, o0, s1, o1, ...]`.
+    /// When one list runs out, the rest of the other list follows in order.
+    ///
+    /// Like [`append`], the nodes are relinked rather than moved, and the capacity and
+    /// allocations of `other` are moved to this list, leaving `other` empty. This is
+    /// `O(min(self.len(), other.len()))` in addition to the cost of moving the
+    /// allocations.
+    ///
+    /// [`append`]: #method.append
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list_a: LinkedList<u32> = LinkedList::new();
+    /// let mut list_b: LinkedList<u32> = LinkedList::new();
+    /// list_a.extend(&[0, 2, 4, 6, 7]);
+    /// list_b.extend(&[1, 3, 5]);
+    ///
+    /// list_a.interleave(&mut list_b);
+    /// assert_eq!(list_a, vec![0, 1, 2, 3, 4, 5, 6, 7]);
+    /// assert!(list_b.is_empty());
+    /// assert_eq!(0, list_b.capacity());
+    /// ```
+    pub fn interleave(&mut self, other: &mut LinkedList<T>) {
+        // self will own the memory, so this must happen before len is updated, and the
+        // global allocator can free memory allocated for any list
+        unsafe { self.take_memory(other) };
+        if other.is_empty() {
+            return;
+        }
+        if self.is_empty() {
+            self.head = other.head;
+            self.tail = other.tail;
+        } else {
+            unsafe {
+                let mut a = self.head;
+                let mut b = other.head;
+                loop {
+                    let a_next = (*a).next;
+                    let b_next = (*b).next;
+                    (*a).next = b;
+                    (*b).prev = a;
+                    if a_next.is_null() {
+                        // the rest of other is still linked after b
+                        self.tail = other.tail;
+                        break;
+                    }
+                    (*b).next = a_next;
+                    (*a_next).prev = b;
+                    if b_next.is_null() {
+                        // the rest of self is now linked after b
+                        break;
+                    }
+                    a = a_next;
+                    b = b_next;
+                }
+            }
+        }
+        self.len += other.len;
+
+        // other is now empty
+        other.head = ptr::null_mut();
+        other.tail = ptr::null_mut();
+        other.len = 0;
+    }
+    /// Concatenates the lists in order into a single list, consuming them.
+    ///
+    /// The result is the first list with the others appended to it using [`append`], so
+    /// no elements are moved and the capacity and allocations of every list are kept.
+    /// Returns an empty list if `lists` is empty.
+    ///
+    /// [`append`]: #method.append
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let lists: Vec<LinkedList<u32>> = vec![
+    ///     (0..3).collect(),
+    ///     LinkedList::new(),
+    ///     (3..5).collect(),
+    /// ];
+    /// let capacity: usize = lists.iter().map(|list| list.capacity()).sum();
+    ///
+    /// let list = LinkedList::concat(lists);
+    /// assert_eq!(list, vec![0, 1, 2, 3, 4]);
+    /// assert_eq!(capacity, list.capacity());
+    ///
+    /// assert!(LinkedList::<u32>::concat(Vec::new()).is_empty());
+    /// ```
+    pub fn concat(lists: Vec<LinkedList<T>>) -> LinkedList<T> {
+        let allocations: usize = lists.iter().map(|list| list.allocations.len()).sum();
+        let mut lists = lists.into_iter();
+        let mut result = match lists.next() {
+            Some(list) => list,
+            None => return LinkedList::new(),
+        };
+        let additional = allocations - result.allocations.len();
+        result.allocations.reserve_exact(additional);
+        for mut list in lists {
+            result.append(&mut list);
+        }
+        result
+    }
+}
+
+impl<T, A: Allocator> LinkedList<T, A> {
+    /// Clones the elements of the slice and appends them to the back of the list, in
+    /// order.
+    ///
+    /// Room for exactly the elements of the slice is reserved up front, so this makes at
+    /// most one allocation, even if the slice is shorter than the chunk size.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<i32> = LinkedList::new();
+    /// list.extend_from_slice(&[1, -2, 3]);
+    ///
+    /// assert_eq!(list, vec![1, -2, 3]);
+    /// assert_eq!(1, list.number_of_allocations());
+    /// assert_eq!(3, list.capacity());
+    /// ```
+    pub fn extend_from_slice(&mut self, other: &[T])
+    where
+        T: Clone,
+    {
+        self.reserve_exact(other.len());
+        for item in other {
+            self.push_back(item.clone());
+        }
+    }
+    /// Creates a new list with clones of the elements, with a clone of `sep` between
+    /// each pair of neighbouring elements. The new list is allocated with room for
+    /// exactly its elements and the same chunk size.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<char> = LinkedList::new();
+    /// list.extend(&['a', 'b', 'c']);
+    ///
+    /// assert_eq!(list.join(&'-'), vec!['a', '-', 'b', '-', 'c']);
+    ///
+    /// let single: LinkedList<char> = Some('a').into_iter().collect();
+    /// assert_eq!(single.join(&'-'), vec!['a']);
+    ///
+    /// let empty: LinkedList<char> = LinkedList::new();
+    /// assert!(empty.join(&'-').is_empty());
+    /// ```
+    pub fn join(&self, sep: &T) -> LinkedList<T, A>
+    where
+        T: Clone,
+        A: Clone,
+    {
+        let cap = (2 * self.len).saturating_sub(1);
+        let mut list =
+            LinkedList::with_capacity_and_chunk_size_in(cap, self.chunk_size, self.alloc.clone());
+        let mut iter = self.iter();
+        if let Some(first) = iter.next() {
+            list.push_back(first.clone());
+        }
+        for item in iter {
+            list.push_back(sep.clone());
+            list.push_back(item.clone());
+        }
+        list
+    }
+    /// Moves the allocations, capacity and unused nodes of `other` to `self`. The caller
+    /// must move the nodes in use by `other` to `self` afterwards, since `other` no
+    /// longer owns their memory.
+    ///
+    /// This must be called before `len` is updated on either list, since the lengths of
+    /// the unused node chains are computed from `len` and `capacity`.
+    ///
+    /// This is unsafe because the allocator of `self` must be able to deallocate memory
+    /// allocated by the allocator of `other`.
+    unsafe fn take_memory(&mut self, other: &mut LinkedList<T, A>) {
+        self.combine_unused_nodes(other);
+
+        // move allocations
+        if self.allocations.len() < other.allocations.len() {
+            mem::swap(&mut self.allocations, &mut other.allocations);
+        }
+        // self.allocations is now the longest array
+        self.allocations.append(&mut other.allocations);
+
+        self.capacity += other.capacity;
+        other.capacity = 0;
+        // allocations is emptied by append
+        debug_assert!(other.allocations.is_empty());
+        // unused_nodes is moved by combine_unused_nodes
+        debug_assert!(other.unused_nodes.is_null());
+    }
+    /// Moves the unused nodes of `other` to `self`, walking the shorter of the two
+    /// chains. The lengths of the chains are computed from `len` and `capacity`, so both
+    /// lists must still have their own values when this is called.
+    fn combine_unused_nodes(&mut self, other: &mut LinkedList<T, A>) {
+        if self.capacity - self.len < other.capacity - other.len {
+            mem::swap(&mut self.unused_nodes, &mut other.unused_nodes);
+        }
+        // self.unused_nodes is now a longer linked list than the one in other
+        // let's find the last node in other.unused_nodes
+        let mut ptr = other.unused_nodes;
+        if ptr.is_null() {
+            // other is null, so we moved all unused_nodes with the swap
+            return;
+        }
+        unsafe {
+            // iterate to the last node
+            while !(*ptr).next.is_null() {
+                ptr = (*ptr).next;
+            }
+            // we now put the unused_nodes in other in front of the ones in self
+            (*ptr).next = self.unused_nodes;
+            self.unused_nodes = other.unused_nodes;
+            other.unused_nodes = ptr::null_mut();
+        }
+    }
+
+    /// Provides a forward iterator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<u32> = LinkedList::new();
+    /// list.push_back(0);
+    /// list.push_back(1);
+    /// list.push_back(2);
+    ///
+    /// let mut iter = list.iter();
+    /// assert_eq!(Some(&0), iter.next());
+    /// assert_eq!(Some(&1), iter.next());
+    /// assert_eq!(Some(&2), iter.next());
+    /// assert_eq!(None, iter.next());
+    /// ```
+    #[inline]
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            head: self.head,
+            tail: self.tail,
+            len: self.len,
+            marker: PhantomData,
+        }
+    }
+    /// Provides a forward iterator with mutable references.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<u32> = LinkedList::new();
+    ///
+    /// list.push_back(0);
+    /// list.push_back(1);
+    /// list.push_back(2);
+    ///
+    /// for element in list.iter_mut() {
+    ///     *element += 10;
+    /// }
+    ///
+    /// let mut iter = list.iter();
+    /// assert_eq!(Some(&10), iter.next());
+    /// assert_eq!(Some(&11), iter.next());
+    /// assert_eq!(Some(&12), iter.next());
+    /// assert_eq!(None, iter.next());
+    /// ```
+    #[inline]
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        IterMut {
+            head: self.head,
+            tail: self.tail,
+            len: self.len,
+            marker: PhantomData,
+        }
+    }
+    /// Calls the closure on every element from the front to the back.
+    ///
+    /// This is the same as `iter().for_each(f)`, but it is implemented as a plain loop
+    /// over the nodes, which may optimize better in hot loops.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<u32> = LinkedList::new();
+    /// list.extend(&[1, 2, 3]);
+    ///
+    /// let mut sum = 0;
+    /// list.for_each_ref(|&i| sum += i);
+    /// assert_eq!(6, sum);
+    /// ```
+    pub fn for_each_ref(&self, mut f: impl FnMut(&T)) {
+        let mut ptr = self.head;
+        while !ptr.is_null() {
+            unsafe {
+                f(&(*ptr).value);
+                ptr = (*ptr).next;
+            }
+        }
+    }
+    /// Calls the closure on a mutable reference to every element from the front to the
+    /// back.
+    ///
+    /// This is the same as `iter_mut().for_each(f)`, but it is implemented as a plain
+    /// loop over the nodes, which may optimize better in hot loops.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<u32> = LinkedList::new();
+    /// list.extend(&[1, 2, 3]);
+    ///
+    /// list.for_each_mut(|i| *i *= 10);
+    /// assert_eq!(list, vec![10, 20, 30]);
+    /// ```
+    pub fn for_each_mut(&mut self, mut f: impl FnMut(&mut T)) {
+        let mut ptr = self.head;
+        while !ptr.is_null() {
+            unsafe {
+                f(&mut (*ptr).value);
+                ptr = (*ptr).next;
+            }
+        }
+    }
+    /// Provides a backward iterator.
+    ///
+    /// This is the same as `iter().rev()`, but the returned iterator is a named type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<u32> = LinkedList::new();
+    /// list.push_back(0);
+    /// list.push_back(1);
+    /// list.push_back(2);
+    ///
+    /// let mut iter = list.iter_rev();
+    /// assert_eq!(Some(&2), iter.next());
+    /// assert_eq!(Some(&1), iter.next());
+    /// assert_eq!(Some(&0), iter.next());
+    /// assert_eq!(None, iter.next());
+    /// ```
+    #[inline]
+    pub fn iter_rev(&self) -> IterRev<'_, T> {
+        IterRev { iter: self.iter() }
+    }
+    /// Provides a backward iterator with mutable references.
+    ///
+    /// This is the same as `iter_mut().rev()`, but the returned iterator is a named type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<u32> = LinkedList::new();
+    /// list.extend(&[0, 1, 2]);
+    ///
+    /// let mut next = 10;
+    /// for element in list.iter_mut_rev() {
+    ///     *element += next;
+    ///     next += 10;
+    /// }
+    ///
+    /// assert_eq!(list, vec![30, 21, 12]);
+    /// ```
+    #[inline]
+    pub fn iter_mut_rev(&mut self) -> IterMutRev<'_, T> {
+        IterMutRev {
+            iter: self.iter_mut(),
+        }
+    }
+    /// Provides two mutable iterators, the first over the elements before `index` and
+    /// the second over the elements from `index` to the back. The iterators cover
+    /// disjoint parts of the list, so both can be used at the same time.
+    ///
+    /// This walks to `index` from the nearest end of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index > len`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<u32> = LinkedList::new();
+    /// list.extend(&[1, 2, 3, 4, 5]);
+    ///
+    /// {
+    ///     let (front, back) = list.iter_mut_split_at(2);
+    ///     for (a, b) in front.zip(back) {
+    ///         std::mem::swap(a, b);
+    ///     }
+    /// }
+    /// assert_eq!(list, vec![3, 4, 1, 2, 5]);
+    /// ```
+    pub fn iter_mut_split_at(&mut self, index: usize) -> (IterMut<'_, T>, IterMut<'_, T>) {
+        assert!(
+            index <= self.len,
+            "index (is {}) should be <= len (is {})",
+            index,
+            self.len
+        );
+        let (before, after) = if index == self.len {
+            (self.tail, ptr::null_mut())
+        } else {
+            let node = self.node_at(index);
+            (unsafe { (*node).prev }, node)
+        };
+        // the iterators only ever look at len nodes, so they never overlap
+        let front = IterMut {
+            head: self.head,
+            tail: before,
+            len: index,
+            marker: PhantomData,
+        };
+        let back = IterMut {
+            head: after,
+            tail: self.tail,
+            len: self.len - index,
+            marker: PhantomData,
+        };
+        (front, back)
+    }
+    /// Provides a forward iterator from the element the cursor points at to the tail of
+    /// the list. This is the immutable counterpart of [`CursorMut::iter_to_tail`].
+    ///
+    /// [`CursorMut::iter_to_tail`]: struct.CursorMut.html#method.iter_to_tail
+    ///
+    /// # Panics
+    ///
+    /// Panics if the cursor points into another list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<u32> = LinkedList::new();
+    /// list.extend(&[1, 2, 3, 4]);
+    ///
+    /// if let Some(cursor) = list.cursor_ref_front() {
+    ///     let cursor = cursor.next().unwrap();
+    ///     let vec: Vec<u32> = list.iter_from_cursor(cursor).cloned().collect();
+    ///     assert_eq!(vec, [2, 3, 4]);
+    /// }
+    /// # else { unreachable!(); }
+    /// ```
+    pub fn iter_from_cursor<'a>(&'a self, cursor: CursorRef<'a, T>) -> Iter<'a, T> {
+        assert!(
+            cursor.list_head == self.head,
+            "cursor does not point into this list"
+        );
+        Iter {
+            head: cursor.cursor,
+            tail: self.tail,
+            len: self.len - cursor.index,
+            marker: PhantomData,
+        }
+    }
+    /// Provides an iterator over groups of `size` consecutive elements. Each group is a
+    /// `Vec` of references to the elements, and the last group may be shorter than
+    /// `size` if the length of the list isn't divisible by `size`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<u32> = LinkedList::new();
+    /// list.extend(&[1, 2, 3, 4, 5]);
+    ///
+    /// let mut chunks = list.chunks(2);
+    /// assert_eq!(Some(vec![&1, &2]), chunks.next());
+    /// assert_eq!(Some(vec![&3, &4]), chunks.next());
+    /// assert_eq!(Some(vec![&5]), chunks.next());
+    /// assert_eq!(None, chunks.next());
+    /// ```
+    pub fn chunks(&self, size: usize) -> Chunks<'_, T> {
+        assert!(size != 0, "chunk size must be non-zero");
+        Chunks {
+            iter: self.iter(),
+            size,
+        }
+    }
+    /// Provides an iterator over all overlapping groups of `size` consecutive elements.
+    /// Each group is a `Vec` of references to the elements. If the list is shorter than
+    /// `size`, the iterator yields nothing.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<u32> = LinkedList::new();
+    /// list.extend(&[1, 2, 3, 4]);
+    ///
+    /// let mut windows = list.windows(2);
+    /// assert_eq!(Some(vec![&1, &2]), windows.next());
+    /// assert_eq!(Some(vec![&2, &3]), windows.next());
+    /// assert_eq!(Some(vec![&3, &4]), windows.next());
+    /// assert_eq!(None, windows.next());
+    ///
+    /// assert_eq!(0, list.windows(5).count());
+    /// ```
+    pub fn windows(&self, size: usize) -> Windows<'_, T> {
+        assert!(size != 0, "window size must be non-zero");
+        Windows {
+            iter: self.iter(),
+            size,
+        }
+    }
+    /// Provides an iterator that repeats the elements of the list endlessly, starting
+    /// over at the front after the back element. If the list is empty, the iterator
+    /// yields nothing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<u32> = LinkedList::new();
+    /// list.extend(&[1, 2, 3]);
+    ///
+    /// let items: Vec<&u32> = list.cycle_iter().take(7).collect();
+    /// assert_eq!(items, [&1, &2, &3, &1, &2, &3, &1]);
+    ///
+    /// let empty: LinkedList<u32> = LinkedList::new();
+    /// assert_eq!(None, empty.cycle_iter().next());
+    /// ```
+    pub fn cycle_iter(&self) -> Cycle<'_, T> {
+        let front = self.cursor_ref_front();
+        Cycle {
+            front,
+            cursor: front,
+        }
+    }
+    /// Creates a `Vec` with clones of the elements, in order. The `Vec` is allocated once
+    /// with room for all of the elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<String> = LinkedList::new();
+    /// list.extend(vec!["a".to_string(), "b".to_string()]);
+    ///
+    /// let vec = list.to_vec();
+    /// assert_eq!(vec, ["a", "b"]);
+    /// ```
+    pub fn to_vec(&self) -> Vec<T>
+    where
+        T: Clone,
+    {
+        let mut vec = Vec::with_capacity(self.len);
+        vec.extend(self.iter().cloned());
+        vec
+    }
+    /// Creates a `Vec` with references to the elements, in order. The `Vec` is allocated
+    /// once with room for all of the elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<u32> = LinkedList::new();
+    /// list.extend(&[1, 2, 3]);
+    ///
+    /// let refs = list.to_vec_refs();
+    /// assert_eq!(refs, [&1, &2, &3]);
+    /// ```
+    pub fn to_vec_refs(&self) -> Vec<&T> {
+        let mut vec = Vec::with_capacity(self.len);
+        vec.extend(self.iter());
+        vec
+    }
+    /// Provides a cursor to the contents of the linked list, positioned at the back
+    /// element, or `None` if the list is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<u32> = LinkedList::new();
+    /// assert!(list.cursor_ref_back().is_none());
+    /// list.push_back(5);
+    /// list.push_back(6);
+    ///
+    /// if let Some(cursor) = list.cursor_ref_back() {
+    ///     assert_eq!(&6, cursor.get());
+    ///     assert_eq!(Some(&5), cursor.prev().map(|cursor| cursor.get()));
+    ///     assert!(cursor.next().is_none());
+    /// }
+    /// # else { unreachable!(); }
+    /// ```
+    #[inline]
+    pub fn cursor_ref_back(&self) -> Option<CursorRef<'_, T>> {
+        if self.tail.is_null() {
+            None
+        } else {
+            Some(CursorRef::create(self, self.tail, self.len - 1))
+        }
+    }
+    /// Provides a cursor to the contents of the linked list, positioned at the front
+    /// element, or `None` if the list is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<u32> = LinkedList::new();
+    /// assert!(list.cursor_ref_front().is_none());
+    /// list.push_front(5);
+    /// list.push_front(6);
+    ///
+    /// if let Some(cursor) = list.cursor_ref_front() {
+    ///     assert_eq!(&6, cursor.get());
+    ///     assert_eq!(Some(&5), cursor.next().map(|cursor| cursor.get()));
+    ///     assert!(cursor.prev().is_none());
+    /// }
+    /// # else { unreachable!(); }
+    /// ```
+    #[inline]
+    pub fn cursor_ref_front(&self) -> Option<CursorRef<'_, T>> {
+        if self.head.is_null() {
+            None
+        } else {
+            Some(CursorRef::create(self, self.head, 0))
+        }
+    }
+    /// Provides a cursor to the contents of the linked list, positioned at the element
+    /// with the given index, or `None` if the index is out of bounds.
+    ///
+    /// The cursor is found by walking from the nearest end of the list, so this is
+    /// `O(min(index, len - index))`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<u32> = LinkedList::new();
+    /// assert!(list.cursor_ref_at(0).is_none());
+    /// list.extend(&[5, 6, 7, 8]);
+    ///
+    /// assert!(list.cursor_ref_at(0).unwrap().ptr_eq(list.cursor_ref_front().unwrap()));
+    /// assert!(list.cursor_ref_at(3).unwrap().ptr_eq(list.cursor_ref_back().unwrap()));
+    ///
+    /// if let Some(cursor) = list.cursor_ref_at(2) {
+    ///     assert_eq!(2, cursor.index());
+    ///     assert_eq!(&7, cursor.get());
+    /// }
+    /// # else { unreachable!(); }
+    ///
+    /// assert!(list.cursor_ref_at(4).is_none());
+    /// ```
+    #[inline]
+    pub fn cursor_ref_at(&self, index: usize) -> Option<CursorRef<'_, T>> {
+        if index >= self.len {
+            None
+        } else {
+            Some(CursorRef::create(self, self.node_at(index), index))
+        }
+    }
+
+    pub fn cursor_mut_back(&mut self) -> Option<CursorMut<'_, T, A>> {
+        if self.tail.is_null() {
+            None
+        } else {
+            let tail = self.tail;
+            let len = self.len;
+            Some(CursorMut::create(self, tail, len - 1))
+        }
+    }
+    pub fn cursor_mut_front(&mut self) -> Option<CursorMut<'_, T, A>> {
+        if self.head.is_null() {
+            None
+        } else {
+            let head = self.head;
+            Some(CursorMut::create(self, head, 0))
+        }
+    }
+    /// Provides a mutable cursor to the contents of the linked list, positioned at the
+    /// element with the given index, or `None` if the index is out of bounds.
+    ///
+    /// The cursor is found by walking from the nearest end of the list, so this is
+    /// `O(min(index, len - index))`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<u32> = LinkedList::new();
+    /// assert!(list.cursor_mut_at(0).is_none());
+    /// list.extend(&[5, 6, 7, 8]);
+    ///
+    /// assert_eq!(&5, list.cursor_mut_at(0).unwrap().get_ref());
+    /// assert_eq!(&8, list.cursor_mut_at(3).unwrap().get_ref());
+    ///
+    /// if let Some(mut cursor) = list.cursor_mut_at(2) {
+    ///     assert_eq!(2, cursor.index());
+    ///     *cursor.get() = 70;
+    /// }
+    /// # else { unreachable!(); }
+    ///
+    /// assert!(list.cursor_mut_at(4).is_none());
+    /// assert_eq!(list, vec![5, 6, 70, 8]);
+    /// ```
+    pub fn cursor_mut_at(&mut self, index: usize) -> Option<CursorMut<'_, T, A>> {
+        if index >= self.len {
+            None
+        } else {
+            let node = self.node_at(index);
+            Some(CursorMut::create(self, node, index))
+        }
+    }
+    /// Provides a mutable cursor positioned at the ghost position of the list, which is
+    /// both before the front and after the back of the list. Moving forward from the
+    /// ghost goes to the front, and moving backward goes to the back.
+    ///
+    /// This allows building a list purely through a cursor, starting from an empty list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<u32> = LinkedList::new();
+    ///
+    /// {
+    ///     let mut cursor = list.cursor_mut();
+    ///     assert_eq!(None, cursor.get());
+    ///     cursor.insert_prev(1);
+    ///     cursor.insert_prev(2);
+    ///     cursor.insert_next(0);
+    ///     assert!(cursor.is_ghost());
+    /// }
+    ///
+    /// assert_eq!(list, vec![0, 1, 2]);
+    /// ```
+    pub fn cursor_mut(&mut self) -> GhostCursorMut<'_, T, A> {
+        let len = self.len;
+        GhostCursorMut::create(self, ptr::null_mut(), len)
+    }
+    /// Provides a mutable cursor positioned at the front element of the list, or at the
+    /// ghost position if the list is empty. Unlike [`cursor_mut_front`], this always
+    /// succeeds, so elements can be inserted into an empty list through the cursor.
+    ///
+    /// [`cursor_mut_front`]: #method.cursor_mut_front
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<u32> = LinkedList::new();
+    ///
+    /// {
+    ///     let mut cursor = list.cursor_mut_front_or_empty();
+    ///     assert!(cursor.is_ghost());
+    ///     cursor.insert_prev(1);
+    ///     cursor.go_next();
+    ///     assert_eq!(Some(&1), cursor.get_ref());
+    ///     cursor.insert_next(2);
+    /// }
+    ///
+    /// assert_eq!(list, vec![1, 2]);
+    /// ```
+    pub fn cursor_mut_front_or_empty(&mut self) -> GhostCursorMut<'_, T, A> {
+        let head = self.head;
+        GhostCursorMut::create(self, head, 0)
+    }
+    /// Provides a mutable cursor positioned at the back element of the list, or at the
+    /// ghost position if the list is empty. Unlike [`cursor_mut_back`], this always
+    /// succeeds, so elements can be inserted into an empty list through the cursor.
+    ///
+    /// [`cursor_mut_back`]: #method.cursor_mut_back
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<u32> = LinkedList::new();
+    /// list.extend(&[1, 2]);
+    ///
+    /// {
+    ///     let mut cursor = list.cursor_mut_back_or_empty();
+    ///     assert_eq!(Some(&2), cursor.get_ref());
+    ///     cursor.insert_next(3);
+    /// }
+    ///
+    /// assert_eq!(list, vec![1, 2, 3]);
+    /// ```
+    pub fn cursor_mut_back_or_empty(&mut self) -> GhostCursorMut<'_, T, A> {
+        let tail = self.tail;
+        let index = self.len.wrapping_sub(1);
+        GhostCursorMut::create(self, tail, index)
+    }
+    /// Provides a cursor positioned at the first element matching the predicate, or
+    /// `None` if no element matches. The search starts at the front of the list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<u32> = LinkedList::new();
+    /// list.extend(&[1, 2, 4, 5, 6]);
+    ///
+    /// if let Some(mut cursor) = list.first_cursor_where(|&i| i > 2) {
+    ///     assert_eq!(2, cursor.index());
+    ///     cursor.insert_prev(3);
+    /// }
+    /// # else { unreachable!(); }
+    /// assert_eq!(list, vec![1, 2, 3, 4, 5, 6]);
+    ///
+    /// assert!(list.first_cursor_where(|&i| i > 10).is_none());
+    /// ```
+    pub fn first_cursor_where(
+        &mut self,
+        mut f: impl FnMut(&T) -> bool,
+    ) -> Option<CursorMut<'_, T, A>> {
+        let mut ptr = self.head;
+        let mut index = 0;
+        while !ptr.is_null() {
+            unsafe {
+                if f(&(*ptr).value) {
+                    return Some(CursorMut::create(self, ptr, index));
+                }
+                ptr = (*ptr).next;
+            }
+            index += 1;
+        }
+        None
+    }
+    /// Provides a cursor positioned at the last element matching the predicate, or
+    /// `None` if no element matches. The search starts at the back of the list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<u32> = LinkedList::new();
+    /// list.extend(&[1, 2, 3, 4, 5]);
+    ///
+    /// if let Some(cursor) = list.last_cursor_where(|&i| i % 2 == 0) {
+    ///     assert_eq!(3, cursor.index());
+    ///     assert_eq!(4, cursor.remove());
+    /// }
+    /// # else { unreachable!(); }
+    /// assert_eq!(list, vec![1, 2, 3, 5]);
+    ///
+    /// assert!(list.last_cursor_where(|&i| i > 10).is_none());
+    /// ```
+    pub fn last_cursor_where(
+        &mut self,
+        mut f: impl FnMut(&T) -> bool,
+    ) -> Option<CursorMut<'_, T, A>> {
+        let mut ptr = self.tail;
+        let mut index = self.len;
+        while !ptr.is_null() {
+            index -= 1;
+            unsafe {
+                if f(&(*ptr).value) {
+                    return Some(CursorMut::create(self, ptr, index));
+                }
+                ptr = (*ptr).prev;
+            }
+        }
+        None
+    }
+
+    /// Add the element to the back of the linked list, and return a cursor positioned at
+    /// the new element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<u32> = LinkedList::new();
+    /// list.push_back(1);
+    ///
+    /// {
+    ///     let mut cursor = list.push_back_cursor(3);
+    ///     assert_eq!(1, cursor.index());
+    ///     cursor.insert_prev(2);
+    ///     cursor.insert_next(4);
+    ///     assert_eq!(2, cursor.index());
+    /// }
+    ///
+    /// assert_eq!(list, vec![1, 2, 3, 4]);
+    /// ```
+    pub fn push_back_cursor(&mut self, value: T) -> CursorMut<'_, T, A> {
+        self.push_back(value);
+        let tail = self.tail;
+        let len = self.len;
+        CursorMut::create(self, tail, len - 1)
+    }
+    /// Add the element to the front of the linked list, and return a cursor positioned at
+    /// the new element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<u32> = LinkedList::new();
+    /// list.push_back(4);
+    ///
+    /// {
+    ///     let mut cursor = list.push_front_cursor(2);
+    ///     assert_eq!(0, cursor.index());
+    ///     cursor.insert_prev(1);
+    ///     cursor.insert_next(3);
+    ///     assert_eq!(1, cursor.index());
+    /// }
+    ///
+    /// assert_eq!(list, vec![1, 2, 3, 4]);
+    /// ```
+    pub fn push_front_cursor(&mut self, value: T) -> CursorMut<'_, T, A> {
+        self.push_front(value);
+        let head = self.head;
+        CursorMut::create(self, head, 0)
+    }
+
+    /// Removes all elements from the `LinkedList`. This method guarantees that capacity
+    /// is unchanged.
+    ///
+    /// This is `O(self.len)` unless `T` has no destructor, in which case it's `O(1)`.
+    ///
+    /// If drop on any element panics, this method won't drop the remaining nodes, but
+    /// the list will still be cleared and no capacity is lost.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<u32> = LinkedList::new();
+    ///
+    /// list.push_front(2);
+    /// list.push_front(1);
+    /// assert_eq!(2, list.len());
+    /// assert_eq!(Some(&1), list.front());
+    ///
+    /// let capacity_before_clear = list.capacity();
+    ///
+    /// list.clear();
+    /// assert_eq!(0, list.len());
+    /// assert_eq!(None, list.front());
+    ///
+    /// // no allocation was lost
+    /// assert_eq!(capacity_before_clear, list.capacity());
+    /// ```
+    pub fn clear(&mut self) {
+        if self.tail.is_null() {
+            return;
+        }
+
+        let tail = self.tail;
+
+        unsafe {
+            // just append unused_nodes to the linked list, and make the result into the
+            // new unused_nodes
+            (*self.tail).next = self.unused_nodes;
+            // unused_nodes is singly linked, so we don't need the other link
+            self.unused_nodes = self.head;
+        }
+        self.head = ptr::null_mut();
+        self.tail = ptr::null_mut();
+        self.len = 0;
+
+        if mem::needs_drop::<T>() {
+            let mut ptr = tail;
+            while !ptr.is_null() {
+                unsafe {
+                    ptr::drop_in_place(&mut (*ptr).value);
+                    ptr = (*ptr).prev;
+                }
+            }
+        }
+    }
+    /// Removes all elements like [`clear`] and changes the size of future allocations
+    /// like [`set_chunk_size`]. The capacity is kept.
+    ///
+    /// [`clear`]: #method.clear
+    /// [`set_chunk_size`]: #method.set_chunk_size
+    ///
+    /// # Panics
+    ///
+    /// This method panics if `chunk_size` is zero. The list is not cleared in that case.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<u32> = LinkedList::with_chunk_size(2);
+    /// list.extend(&[1, 2, 3]);
+    /// let capacity = list.capacity();
+    ///
+    /// list.clear_and_set_chunk_size(10);
+    /// assert!(list.is_empty());
+    /// assert_eq!(10, list.chunk_size());
+    /// assert_eq!(capacity, list.capacity());
+    /// ```
+    pub fn clear_and_set_chunk_size(&mut self, chunk_size: usize) {
+        self.set_chunk_size(chunk_size);
+        self.clear();
+    }
+
+    /// Moves all elements out of the list into the returned list, leaving this list
+    /// empty.
+    ///
+    /// Unlike [`clear`], the capacity is moved along with the elements, since the
+    /// allocations are owned by the returned list. This list keeps its chunk size, but
+    /// has no capacity afterwards.
+    ///
+    /// [`clear`]: #method.clear
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<u32> = LinkedList::with_chunk_size(16);
+    /// list.extend(&[1, 2, 3]);
+    /// let capacity = list.capacity();
+    ///
+    /// let taken = list.take();
+    /// assert_eq!(taken, vec![1, 2, 3]);
+    /// assert_eq!(capacity, taken.capacity());
+    ///
+    /// assert!(list.is_empty());
+    /// assert_eq!(0, list.capacity());
+    /// assert_eq!(16, list.chunk_size());
+    /// ```
+    pub fn take(&mut self) -> LinkedList<T, A>
+    where
+        A: Clone,
+    {
+        let empty = LinkedList::with_chunk_size_in(self.chunk_size, self.alloc.clone());
+        mem::replace(self, empty)
+    }
+
+    /// Removes the elements in `range` and inserts the elements of `replace_with` in
+    /// their place, like [`Vec::splice`]. Returns an iterator over the removed elements.
+    ///
+    /// The nodes of the removed elements are reused by the replacement elements, and any
+    /// remaining ones stay in the list as unused capacity. Unlike `Vec::splice`, this
+    /// happens before the method returns, and the removed values are moved into a new
+    /// list with room for exactly them and the same chunk size, which the iterator owns.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the start of the range is greater than the end, or if the end is greater
+    /// than the length of the list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<u32> = LinkedList::new();
+    /// list.extend(&[1, 2, 3, 4]);
+    ///
+    /// let removed: Vec<u32> = list.splice(1..3, vec![7, 8, 9]).collect();
+    /// assert_eq!(removed, [2, 3]);
+    /// assert_eq!(list, vec![1, 7, 8, 9, 4]);
+    ///
+    /// // an empty range only inserts
+    /// list.splice(5.., vec![10]);
+    /// assert_eq!(list, vec![1, 7, 8, 9, 4, 10]);
+    /// ```
+    ///
+    /// [`Vec::splice`]: https://doc.rust-lang.org/std/vec/struct.Vec.html#method.splice
+    pub fn splice<R, I>(&mut self, range: R, replace_with: I) -> IntoIter<T, A>
+    where
+        R: RangeBounds<usize>,
+        I: IntoIterator<Item = T>,
+        A: Clone,
+    {
+        let start = match range.start_bound() {
+            Bound::Included(&start) => start,
+            Bound::Excluded(&start) => start.checked_add(1).expect("range start overflow"),
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&end) => end.checked_add(1).expect("range end overflow"),
+            Bound::Excluded(&end) => end,
+            Bound::Unbounded => self.len,
+        };
+        assert!(
+            start <= end,
+            "range start (is {}) should be <= range end (is {})",
+            start,
+            end
+        );
+        assert!(
+            end <= self.len,
+            "range end (is {}) should be <= len (is {})",
+            end,
+            self.len
+        );
+
+        let mut removed = LinkedList::with_capacity_and_chunk_size_in(
+            end - start,
+            self.chunk_size,
+            self.alloc.clone(),
+        );
+        // the node the replacement is inserted before, or null to insert at the back
+        let mut next = if start == self.len {
+            ptr::null_mut()
+        } else {
+            self.node_at(start)
+        };
+        for _ in start..end {
+            unsafe {
+                let node = next;
+                next = (*node).next;
+                removed.push_back(self.remove_node(node));
+            }
+        }
+
+        for value in replace_with {
+            // the list is kept valid after every insertion, in case the iterator panics
+            let prev = if next.is_null() {
+                self.tail
+            } else {
+                unsafe { (*next).prev }
+            };
+            let node = self.new_node(next, prev, value);
+            self.len += 1;
+            unsafe {
+                if prev.is_null() {
+                    self.head = node;
+                } else {
+                    (*prev).next = node;
+                }
+                if next.is_null() {
+                    self.tail = node;
+                } else {
+                    (*next).prev = node;
+                }
+            }
+        }
+        removed.into_iter()
+    }
+
+    /// Shortens the list, keeping the first `len` elements and dropping the rest. This
+    /// has no effect if `len` is greater than the length of the list, and capacity is
+    /// unchanged in any case.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<u32> = LinkedList::new();
+    /// list.extend(&[1, 2, 3, 4, 5]);
+    ///
+    /// list.truncate(2);
+    /// assert_eq!(list, vec![1, 2]);
+    ///
+    /// list.truncate(5);
+    /// assert_eq!(list, vec![1, 2]);
+    /// ```
+    pub fn truncate(&mut self, len: usize) {
+        if len == 0 {
+            self.clear();
+        } else {
+            while self.len > len {
+                self.pop_back();
+            }
+        }
+    }
+    /// Resizes the list so that `len` is equal to `new_len`.
+    ///
+    /// If `new_len` is greater than `len`, the list is extended by the difference, with
+    /// each additional slot filled with `value`. If `new_len` is less than `len`, the list
+    /// is truncated.
+    ///
+    /// This only allocates if the list doesn't already have enough capacity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<&str> = LinkedList::new();
+    /// list.push_back("hello");
+    /// let capacity = list.capacity();
+    ///
+    /// list.resize(3, "world");
+    /// assert_eq!(list, vec!["hello", "world", "world"]);
+    /// // there was already room for the new elements
+    /// assert_eq!(capacity, list.capacity());
+    ///
+    /// list.resize(1, "unused");
+    /// assert_eq!(list, vec!["hello"]);
+    ///
+    /// // growing past the capacity allocates
+    /// list.resize(capacity + 1, "world");
+    /// assert_eq!(capacity + 1, list.len());
+    /// assert!(list.capacity() > capacity);
+    ///
+    /// list.resize(0, "unused");
+    /// assert!(list.is_empty());
+    /// ```
+    pub fn resize(&mut self, new_len: usize, value: T)
+    where
+        T: Clone,
+    {
+        if new_len > self.len {
+            self.reserve(new_len - self.len);
+            while self.len + 1 < new_len {
+                self.push_back(value.clone());
+            }
+            self.push_back(value);
+        } else {
+            self.truncate(new_len);
+        }
+    }
+    /// Resizes the list so that `len` is equal to `new_len`.
+    ///
+    /// If `new_len` is greater than `len`, the list is extended by the difference, with
+    /// each additional slot filled with the result of calling the closure `f`. If
+    /// `new_len` is less than `len`, the list is truncated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<u32> = LinkedList::new();
+    /// list.push_back(1);
+    ///
+    /// let mut next = 1;
+    /// list.resize_with(4, || { next *= 2; next });
+    /// assert_eq!(list, vec![1, 2, 4, 8]);
+    ///
+    /// // shrinking doesn't call the closure
+    /// list.resize_with(2, || unreachable!());
+    /// assert_eq!(list, vec![1, 2]);
+    /// ```
+    pub fn resize_with(&mut self, new_len: usize, mut f: impl FnMut() -> T) {
+        if new_len > self.len {
+            self.reserve(new_len - self.len);
+            while self.len < new_len {
+                self.push_back(f());
+            }
+        } else {
+            self.truncate(new_len);
+        }
+    }
+
+    /// Returns the number of elements the list can hold without allocating.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<u32> = LinkedList::with_capacity(48);
+    /// assert_eq!(48, list.capacity());
+    /// ```
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+    /// Returns the number of items in the list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<u32> = LinkedList::new();
+    ///
+    /// list.push_front(2);
+    /// assert_eq!(1, list.len());
+    ///
+    /// list.push_back(3);
+    /// assert_eq!(2, list.len());
+    ///
+    /// list.pop_front();
+    /// assert_eq!(1, list.len());
+    /// ```
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+    /// Returns `true` if the list is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<u32> = LinkedList::new();
+    /// assert!(list.is_empty());
+    ///
+    /// list.push_back(3);
+    /// assert!(!list.is_empty());
+    ///
+    /// list.pop_front();
+    /// assert!(list.is_empty());
+    /// ```
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Change the size of future allocations. This has no effect on previous allocations.
+    ///
+    /// When some operation increases the size of the linked list past the capacity, the
+    /// linked list will allocate at least `chunk_size` nodes in one allocation.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if `chunk_size` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<u32> = LinkedList::new();
+    /// // default chunk size is 64
+    /// assert_eq!(64, list.chunk_size());
+    ///
+    /// list.set_chunk_size(3);
+    /// assert_eq!(3, list.chunk_size());
+    ///
+    /// // add an element, which allocates 3 nodes
+    /// list.push_back(4);
+    /// assert_eq!(3, list.capacity());
+    /// ```
+    #[inline]
+    pub fn set_chunk_size(&mut self, chunk_size: usize) {
+        assert!(chunk_size > 0);
+        self.chunk_size = chunk_size;
+    }
+    /// Returns the minimum size of future allocations.  See [`set_chunk_size`].
+    ///
+    /// [`set_chunk_size`]: #method.set_chunk_size
+    #[inline]
+    pub fn chunk_size(&self) -> usize {
+        self.chunk_size
+    }
+    /// Returns the number of allocations owned by this list.
+    ///
+    /// Every allocation holds a chunk of nodes, so a large number of allocations
+    /// compared to the capacity means that the nodes are spread out in memory.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<u32> = LinkedList::with_capacity(3);
+    /// assert_eq!(1, list.number_of_allocations());
+    ///
+    /// let mut other: LinkedList<u32> = LinkedList::with_capacity(3);
+    /// list.append(&mut other);
+    /// assert_eq!(2, list.number_of_allocations());
+    /// assert_eq!(0, other.number_of_allocations());
+    /// ```
+    #[inline]
+    pub fn number_of_allocations(&self) -> usize {
+        self.allocations.len()
+    }
+    /// Returns the number of bytes allocated for the nodes of this list.
+    ///
+    /// This counts the storage for every node, including unused nodes, but doesn't
+    /// include the memory used to keep track of the allocations themselves.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let list: LinkedList<u32> = LinkedList::with_capacity(10);
+    /// assert!(list.allocated_bytes() >= 10 * std::mem::size_of::<u32>());
+    ///
+    /// let empty: LinkedList<u32> = LinkedList::new();
+    /// assert_eq!(0, empty.allocated_bytes());
+    /// ```
+    pub fn allocated_bytes(&self) -> usize {
+        let nodes: usize = self.allocations.iter().map(|&(_, cap)| cap).sum();
+        nodes * mem::size_of::<LinkedNode<T>>()
+    }
+
+    /// Moves every element into a single new allocation with room for exactly `len`
+    /// nodes, and deallocates all previous allocations.
+    ///
+    /// This is useful after operations such as [`append`] have left the list spread over
+    /// many small allocations, since the elements will be stored next to each other in
+    /// order afterwards. Any excess capacity is released, and if the list is empty, all
+    /// memory owned by the list is deallocated.
+    ///
+    /// The values are moved, so no destructors or other user code runs, and this method
+    /// can only panic if the allocation fails, in which case the list is unchanged.
+    ///
+    /// This is `O(len + number_of_allocations)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<u32> = LinkedList::new();
+    /// for i in 0..4 {
+    ///     let mut other: LinkedList<u32> = LinkedList::new();
+    ///     other.push_back(i);
+    ///     list.append(&mut other);
+    /// }
+    /// assert_eq!(4, list.number_of_allocations());
+    ///
+    /// list.compact();
+    ///
+    /// assert_eq!(1, list.number_of_allocations());
+    /// assert_eq!(4, list.capacity());
+    /// assert_eq!(list, vec![0, 1, 2, 3]);
+    /// ```
+    ///
+    /// [`append`]: #method.append
+    pub fn compact(&mut self)
+    where
+        A: Clone,
+    {
+        let mut list = LinkedList::with_capacity_and_chunk_size_in(
+            self.len,
+            self.chunk_size,
+            self.alloc.clone(),
+        );
+
+        unsafe {
+            // this doesn't allocate, since list has room for every element
+            let mut ptr = self.head;
+            while !ptr.is_null() {
+                list.push_back(ptr::read(&(*ptr).value));
+                ptr = (*ptr).next;
+            }
+        }
+
+        // the values have been moved, so the old list must not drop them
+        self.head = ptr::null_mut();
+        self.tail = ptr::null_mut();
+        self.len = 0;
+
+        // the old allocations are deallocated when list is dropped
+        mem::swap(self, &mut list);
+    }
+    /// Frees allocations that contain no elements, as long as the capacity stays at
+    /// least `min_capacity`. Allocations that contain any element are kept, so the
+    /// capacity may remain larger than both `min_capacity` and the length of the list.
+    /// Use [`compact`] to move the elements into a single allocation instead.
+    ///
+    /// The elements are not moved, so references obtained from cursors before the call
+    /// would still point to the same values.
+    ///
+    /// This is `O(unused_capacity * log(number_of_allocations))`.
+    ///
+    /// [`compact`]: #method.compact
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<u32> = LinkedList::with_chunk_size(4);
+    /// for i in 0..12 {
+    ///     list.push_back(i);
+    /// }
+    /// assert_eq!(12, list.capacity());
+    ///
+    /// // the last two allocations are now unused
+    /// list.truncate(4);
+    ///
+    /// list.shrink_to(6);
+    /// assert_eq!(8, list.capacity());
+    /// list.shrink_to(0);
+    /// assert_eq!(4, list.capacity());
+    /// assert_eq!(list, vec![0, 1, 2, 3]);
+    /// ```
+    pub fn shrink_to(&mut self, min_capacity: usize) {
+        if self.capacity <= min_capacity || self.unused_nodes.is_null() {
+            return;
+        }
+
+        // sort the allocations by address, so we can find the allocation containing a
+        // node with a binary search
+        self.allocations.sort_by_key(|&(base, _)| base as usize);
+        let allocations = &self.allocations;
+        let allocation_of = |node: *mut LinkedNode<T>| -> usize {
+            match allocations.binary_search_by_key(&(node as usize), |&(base, _)| base as usize) {
+                Ok(i) => i,
+                Err(i) => i - 1,
+            }
+        };
+
+        let mut unused = vec![0; allocations.len()];
+        let mut ptr = self.unused_nodes;
+        while !ptr.is_null() {
+            unused[allocation_of(ptr)] += 1;
+            ptr = unsafe { (*ptr).next };
+        }
+
+        let mut capacity = self.capacity;
+        let free: Vec<bool> = allocations
+            .iter()
+            .zip(&unused)
+            .map(|(&(_, cap), &unused)| {
+                if unused == cap && capacity - cap >= min_capacity {
+                    capacity -= cap;
+                    true
+                } else {
+                    false
+                }
+            })
+            .collect();
+        if capacity == self.capacity {
+            return;
+        }
+
+        // remove the nodes in the freed allocations from unused_nodes
+        let mut head = ptr::null_mut();
+        let mut last: *mut LinkedNode<T> = ptr::null_mut();
+        let mut ptr = self.unused_nodes;
+        while !ptr.is_null() {
+            let next = unsafe { (*ptr).next };
+            if !free[allocation_of(ptr)] {
+                if last.is_null() {
+                    head = ptr;
+                } else {
+                    unsafe {
+                        (*last).next = ptr;
+                    }
+                }
+                last = ptr;
+            }
+            ptr = next;
+        }
+        if !last.is_null() {
+            unsafe {
+                (*last).next = ptr::null_mut();
+            }
+        }
+        self.unused_nodes = head;
+        self.capacity = capacity;
+
+        let mut free = free.into_iter();
+        let alloc = &self.alloc;
+        self.allocations.retain(|&(base, cap)| {
+            if free.next().unwrap() {
+                unsafe {
+                    deallocate_nodes(alloc, base, cap);
+                }
+                false
+            } else {
+                true
+            }
+        });
+    }
+    /// Frees every allocation that contains no elements. This is the same as
+    /// `shrink_to(0)`, see [`shrink_to`] for details.
+    ///
+    /// [`shrink_to`]: #method.shrink_to
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<u32> = LinkedList::with_capacity(10);
+    /// list.push_back(1);
+    ///
+    /// // the single allocation contains an element, so it is kept
+    /// list.shrink_to_fit();
+    /// assert_eq!(10, list.capacity());
+    ///
+    /// list.clear();
+    /// list.shrink_to_fit();
+    /// assert_eq!(0, list.capacity());
+    /// ```
+    pub fn shrink_to_fit(&mut self) {
+        self.shrink_to(0);
+    }
+
+    /// Reserves capacity for at least `additional` more elements to be inserted in the
+    /// list. This method will not reserve less than [`chunk_size`] nodes to avoid
+    /// frequent allocations.
+    ///
+    /// This is `O(allocation_size)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the new capacity overflows `usize`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<u32> = LinkedList::with_capacity(5);
+    /// assert_eq!(5, list.capacity());
+    ///
+    /// list.push_back(3);
+    /// list.reserve(84); // 84 is larger than the default chunk size
+    ///
+    /// // there's already one element in the list, so it increases the capacity to 85
+    /// // the actual size of the allocation is 80, since the previous capacity was 5
+    /// assert_eq!(85, list.capacity());
+    /// ```
+    ///
+    /// [`chunk_size`]: #method.chunk_size
+    pub fn reserve(&mut self, additional: usize) {
+        let free_capacity = self.capacity() - self.len();
+        if free_capacity >= additional {
+            return;
+        }
+        let required = self.len.checked_add(additional).expect("capacity overflow");
+        let to_allocate = required - self.capacity;
+
+        let chunk_size = self.chunk_size;
+        if to_allocate < chunk_size {
+            self.allocate(chunk_size);
+        } else {
+            self.allocate(to_allocate);
+        }
+    }
+    /// Reserves capacity for exactly `additional` more elements to be inserted in the
+    /// list.
+    ///
+    /// This is `O(additional)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the new capacity overflows `usize`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_list::LinkedList;
+    ///
+    /// let mut list: LinkedList<u32> = LinkedList::with_capacity(5);
+    /// assert_eq!(5, list.capacity());
+    ///
+    /// list.push_back(3);
+    /// list.reserve_exact(5);
+    ///
+    /// // there's already one element in the list, so it increases the capacity to 6
+    /// // the actual size of the allocation is 1, since the previous capacity was 5
+    /// assert_eq!(6, list.capacity());
+    /// ```
+    ///
+    /// [`chunk_size`]: #method.chunk_size
+    pub fn reserve_exact(&mut self, additional: usize) {
+        let free_capacity = self.capacity() - self.len();
+        if free_capacity >= additional {
+            return;
+        }
+        let required = self.len.checked_add(additional).expect("capacity overflow");
+        let to_allocate = required - self.capacity;
+        self.allocate(to_allocate);
+    }
+
+    /// Returns the number of nodes to reserve for an iterator with the given size hint.
+    ///
+    /// The upper bound is used when it's close to the lower bound, so that iterators
+    /// with a tight upper bound don't cause several allocations while filling the list,
+    /// but iterators with a loose upper bound such as `filter` won't make us reserve lots
+    /// of memory that is never used.
+    fn reserve_hint(&self, (lower, upper): (usize, Option<usize>)) -> usize {
+        match upper {
+            Some(upper) if upper.saturating_sub(lower) <= cmp::max(lower, self.chunk_size) => upper,
+            _ => lower,
+        }
+    }
+    /// Returns the node at the given index by walking from the nearest end.
+    ///
+    /// The index must be in bounds.
+    fn node_at(&self, index: usize) -> *mut LinkedNode<T> {
+        debug_assert!(index < self.len);
+        unsafe {
+            if index < self.len / 2 {
+                let mut ptr = self.head;
+                for _ in 0..index {
+                    ptr = (*ptr).next;
+                }
+                ptr
+            } else {
+                let mut ptr = self.tail;
+                for _ in index + 1..self.len {
+                    ptr = (*ptr).prev;
+                }
+                ptr
+            }
+        }
+    }
+    /// Returns the first node whose value is equal to `x`, searching from the front.
+    fn find_node(&self, x: &T) -> Option<*mut LinkedNode<T>>
+    where
+        T: PartialEq,
+    {
+        let mut ptr = self.head;
+        while !ptr.is_null() {
+            unsafe {
+                if (*ptr).value == *x {
+                    return Some(ptr);
+                }
+                ptr = (*ptr).next;
+            }
+        }
+        None
+    }
+    /// Unlinks a node from its neighbours, updating the head and tail if needed. The
+    /// links of the node itself are left unchanged, and `len` is not updated, so the
+    /// caller must link the node back in somewhere.
+    unsafe fn unlink_node(&mut self, node: *mut LinkedNode<T>) {
+        let prev = (*node).prev;
+        let next = (*node).next;
+        if prev.is_null() {
+            self.head = next;
+        } else {
+            (*prev).next = next;
+        }
+        if next.is_null() {
+            self.tail = prev;
+        } else {
+            (*next).prev = prev;
+        }
+    }
+    /// Swaps the positions of two neighbouring nodes in the list, where `b` is the node
+    /// directly after `a`.
+    unsafe fn swap_adjacent(&mut self, a: *mut LinkedNode<T>, b: *mut LinkedNode<T>) {
+        debug_assert!((*a).next == b);
+        let prev = (*a).prev;
+        let next = (*b).next;
+
+        if prev.is_null() {
+            self.head = b;
+        } else {
+            (*prev).next = b;
+        }
+        if next.is_null() {
+            self.tail = a;
+        } else {
+            (*next).prev = a;
+        }
+
+        (*b).prev = prev;
+        (*b).next = a;
+        (*a).prev = b;
+        (*a).next = next;
+    }
+    /// Unlinks the node from the list, discards it and returns the value.
+    unsafe fn remove_node(&mut self, node: *mut LinkedNode<T>) -> T {
+        let prev = (*node).prev;
+        let next = (*node).next;
+
+        if prev.is_null() {
+            self.head = next;
+        } else {
+            (*prev).next = next;
+        }
+        if next.is_null() {
+            self.tail = prev;
+        } else {
+            (*next).prev = prev;
+        }
+
+        let value = ptr::read(&(*node).value);
+        self.discard_node(node);
+        self.len -= 1;
+        value
+    }
+    fn discard_node(&mut self, node: *mut LinkedNode<T>) {
+        unsafe {
+            (*node).next = self.unused_nodes;
+        }
+        self.unused_nodes = node;
+    }
+    fn new_node(
+        &mut self,
+        next: *mut LinkedNode<T>,
+        prev: *mut LinkedNode<T>,
+        value: T,
+    ) -> *mut LinkedNode<T> {
+        unsafe {
+            if self.unused_nodes.is_null() {
+                let chunk_size = self.chunk_size;
+                self.allocate(chunk_size);
+            }
+            let node = self.unused_nodes;
+            self.unused_nodes = (*node).next;
+
+            ptr::write(node, LinkedNode { next, prev, value });
+            node
+        }
+    }
+
+    fn allocate(&mut self, amount: usize) {
+        if amount == 0 {
+            return;
+        }
+        // check this up front so we fail with the same message as Vec, rather than
+        // overflowing the capacity counter
+        let too_many_bytes = match amount.checked_mul(mem::size_of::<LinkedNode<T>>()) {
+            Some(bytes) => bytes > isize::MAX as usize,
+            None => true,
+        };
+        let capacity_overflow = too_many_bytes || self.capacity.checked_add(amount).is_none();
+        if capacity_overflow {
+            panic!("capacity overflow");
+        }
+        // only the requested amount of nodes is used, even if the allocator returned more
+        // memory, so the capacity is always exactly what was asked for
+        let base = allocator::allocate(&self.alloc, node_layout::<T>(amount)) as *mut LinkedNode<T>;
+        self.capacity += amount;
+
+        self.allocations.push((base, amount));
+
+        // add them to the unused_nodes list in reverse order, so they end up in the
+        // correct order if lots of elements are added with push_back
+        for i in (0..amount).rev() {
+            let ptr = unsafe { base.add(i) };
+
+            unsafe {
+                (*ptr).next = self.unused_nodes;
+            }
+            self.unused_nodes = ptr;
+        }
+    }
+}
+
+impl<T, A: Allocator> Drop for LinkedList<T, A> {
+    fn drop(&mut self) {
+        unsafe {
+            let mut ptr = self.head;
+            while !ptr.is_null() {
+                ptr::drop_in_place(&mut (*ptr).value);
+                ptr = (*ptr).next;
+            }
+
+            for &(base, capacity) in &self.allocations {
+                deallocate_nodes(&self.alloc, base, capacity);
+            }
+        }
+    }
+}
+impl<T> Default for LinkedList<T> {
+    fn default() -> LinkedList<T> {
+        LinkedList::new()
+    }
+}
+impl<T: Clone, A: Allocator + Clone> Clone for LinkedList<T, A> {
+    fn clone(&self) -> LinkedList<T, A> {
+        let mut list = LinkedList::with_capacity_and_chunk_size_in(
+            self.len(),
+            self.chunk_size,
+            self.alloc.clone(),
+        );
+        for item in self.iter() {
+            list.push_back(item.clone());
+        }
+        list
+    }
+    fn clone_from(&mut self, source: &Self) {
+        self.chunk_size = source.chunk_size;
+        // reuse the existing values where possible, and only add or remove the elements
+        // making up the difference in length
+        self.truncate(source.len());
+        let mut source_iter = source.iter();
+        for (item, source_item) in self.iter_mut().zip(&mut source_iter) {
+            item.clone_from(source_item);
+        }
+        self.reserve_exact(source_iter.len());
+        for item in source_iter {
+            self.push_back(item.clone());
+        }
+    }
+}
+impl<T> FromIterator<T> for LinkedList<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let iter = iter.into_iter();
+        let mut list = LinkedList::new();
+        let cap = list.reserve_hint(iter.size_hint());
+        list.allocate(cap);
+        for item in iter {
+            list.push_back(item);
+        }
+        list
+    }
+}
+impl<T> From<alloc::collections::LinkedList<T>> for LinkedList<T> {
+    fn from(std_list: alloc::collections::LinkedList<T>) -> Self {
+        let mut list = LinkedList::with_capacity(std_list.len());
+        for item in std_list {
+            list.push_back(item);
+        }
+        list
+    }
+}
+impl<T> From<LinkedList<T>> for alloc::collections::LinkedList<T> {
+    fn from(list: LinkedList<T>) -> Self {
+        list.into_iter().collect()
+    }
+}
+impl<T: Eq, A: Allocator> Eq for LinkedList<T, A> {}
+impl<T: PartialEq<U>, U, A: Allocator, B: Allocator> PartialEq<LinkedList<U, B>>
+    for LinkedList<T, A>
+{
+    fn eq(&self, other: &LinkedList<U, B>) -> bool {
+        if self.len() != other.len() {
+            return false;
+        }
+        for (a, b) in self.iter().zip(other.iter()) {
+            if a != b {
+                return false;
+            }
+        }
+        true
+    }
+}
+impl<T: PartialEq<U>, U, A: Allocator> PartialEq<Vec<U>> for LinkedList<T, A> {
+    fn eq(&self, other: &Vec<U>) -> bool {
+        if self.len() != other.len() {
+            return false;
+        }
+        for (a, b) in self.iter().zip(other.iter()) {
+            if a != b {
+                return false;
+            }
+        }
+        true
+    }
+}
+impl<T: PartialEq<U>, U, A: Allocator> PartialEq<[U]> for LinkedList<T, A> {
+    fn eq(&self, other: &[U]) -> bool {
+        if self.len() != other.len() {
+            return false;
+        }
+        for (a, b) in self.iter().zip(other.iter()) {
+            if a != b {
+                return false;
+            }
+        }
+        true
+    }
+}
+impl<'a, T: PartialEq<U>, U, A: Allocator> PartialEq<&'a [U]> for LinkedList<T, A> {
+    fn eq(&self, other: &&'a [U]) -> bool {
+        if self.len() != other.len() {
+            return false;
+        }
+        for (a, b) in self.iter().zip(other.iter()) {
+            if a != b {
+                return false;
+            }
+        }
+        true
+    }
+}
+impl<'a, T: PartialEq<U>, U, A: Allocator> PartialEq<&'a mut [U]> for LinkedList<T, A> {
+    fn eq(&self, other: &&'a mut [U]) -> bool {
+        if self.len() != other.len() {
+            return false;
+        }
+        for (a, b) in self.iter().zip(other.iter()) {
+            if a != b {
+                return false;
+            }
+        }
+        true
+    }
+}
+/// # Examples
+///
+/// ```
+/// use linked_list::LinkedList;
+///
+/// let mut list: LinkedList<u32> = LinkedList::new();
+/// list.extend(&[1, 2, 3]);
+///
+/// assert_eq!(list, [1, 2, 3]);
+/// assert_ne!(list, [1, 2]);
+/// ```
+impl<T: PartialEq<U>, U, A: Allocator, const N: usize> PartialEq<[U; N]> for LinkedList<T, A> {
+    fn eq(&self, other: &[U; N]) -> bool {
+        if self.len() != other.len() {
+            return false;
+        }
+        for (a, b) in self.iter().zip(other.iter()) {
+            if a != b {
+                return false;
+            }
+        }
+        true
+    }
+}
+/// # Examples
+///
+/// ```
+/// use linked_list::LinkedList;
+/// use std::collections::VecDeque;
+///
+/// let mut list: LinkedList<u32> = LinkedList::new();
+/// list.extend(&[1, 2, 3]);
+///
+/// let mut deque: VecDeque<u32> = VecDeque::new();
+/// deque.push_back(2);
+/// deque.push_back(3);
+/// deque.push_front(1);
+///
+/// assert_eq!(list, deque);
+/// deque.pop_back();
+/// assert_ne!(list, deque);
+/// ```
+impl<T: PartialEq<U>, U, A: Allocator> PartialEq<VecDeque<U>> for LinkedList<T, A> {
+    fn eq(&self, other: &VecDeque<U>) -> bool {
+        if self.len() != other.len() {
+            return false;
+        }
+        for (a, b) in self.iter().zip(other.iter()) {
+            if a != b {
+                return false;
+            }
+        }
+        true
+    }
+}
+impl<T: Ord, A: Allocator> Ord for LinkedList<T, A> {
+    fn cmp(&self, other: &LinkedList<T, A>) -> Ordering {
+        for (a, b) in self.iter().zip(other.iter()) {
+            match a.cmp(b) {
+                Ordering::Equal => {}
+                ordering => {
+                    return ordering;
+                }
+            }
+        }
+        Ordering::Equal
+    }
+}
+impl<T: PartialOrd<U>, U, A: Allocator, B: Allocator> PartialOrd<LinkedList<U, B>>
+    for LinkedList<T, A>
+{
+    fn partial_cmp(&self, other: &LinkedList<U, B>) -> Option<Ordering> {
+        for (a, b) in self.iter().zip(other.iter()) {
+            match a.partial_cmp(b) {
+                Some(Ordering::Equal) => {}
+                ordering => {
+                    return ordering;
+                }
+            }
+        }
+        Some(Ordering::Equal)
+    }
+}
+impl<T, A: Allocator> Extend<T> for LinkedList<T, A> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let iter = iter.into_iter();
+        let additional = self.reserve_hint(iter.size_hint());
+        self.reserve(additional);
+        for item in iter {
+            self.push_back(item);
+        }
+    }
+}
+/// Extend the list with clones of the borrowed elements.
+///
+/// # Examples
+///
+/// ```
+/// use linked_list::LinkedList;
+///
+/// let strings = vec!["hello".to_string(), "world".to_string()];
+///
+/// let mut list: LinkedList<String> = LinkedList::new();
+/// list.extend(&strings[..]);
+/// list.extend(strings.iter());
+///
+/// assert_eq!(list, vec!["hello", "world", "hello", "world"]);
+/// ```
+impl<'a, T: 'a + Clone, A: Allocator> Extend<&'a T> for LinkedList<T, A> {
+    fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+        let iter = iter.into_iter();
+        let additional = self.reserve_hint(iter.size_hint());
+        self.reserve(additional);
+        for item in iter {
+            self.push_back(item.clone());
+        }
+    }
+}
+impl<T, A: Allocator> IntoIterator for LinkedList<T, A> {
+    type Item = T;
+    type IntoIter = IntoIter<T, A>;
+    fn into_iter(self) -> IntoIter<T, A> {
+        IntoIter { list: self }
+    }
+}
+impl<'a, T, A: Allocator> IntoIterator for &'a LinkedList<T, A> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+    fn into_iter(self) -> Iter<'a, T> {
+        self.iter()
+    }
+}
+impl<'a, T, A: Allocator> IntoIterator for &'a mut LinkedList<T, A> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+    fn into_iter(self) -> IterMut<'a, T> {
+        self.iter_mut()
+    }
+}
+impl<T: Hash, A: Allocator> Hash for LinkedList<T, A> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        // hash the length first like slices do, so lists that are prefixes of each other
+        // don't collide when hashed in sequence
+        state.write_usize(self.len);
+        for item in self.iter() {
+            item.hash(state);
+        }
+    }
+}
+impl<T: fmt::Debug, A: Allocator> fmt::Debug for LinkedList<T, A> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        let mut out = f.debug_list();
+        for item in self.iter() {
+            out.entry(item);
+        }
+        out.finish()
+    }
+}
+/// Formats the list as `[a, b, c]` using the `Display` implementation of the elements.
+/// Formatting options such as width and precision are applied to each element. An empty
+/// list is formatted as `[]`.
+///
+/// # Examples
+///
+/// ```
+/// use linked_list::LinkedList;
+///
+/// let mut list: LinkedList<&str> = LinkedList::new();
+/// list.push_back("hello");
+/// list.push_back("world");
+///
+/// assert_eq!("[hello, world]", list.to_string());
+/// assert_eq!("[hello, world]", format!("{}", list));
+///
+/// let list: LinkedList<f64> = vec![1.0, 2.5].into_iter().collect();
+/// assert_eq!("[1.00, 2.50]", format!("{:.2}", list));
+/// ```
+impl<T: fmt::Display, A: Allocator> fmt::Display for LinkedList<T, A> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        f.write_str("[")?;
+        for (i, item) in self.iter().enumerate() {
+            if i != 0 {
+                f.write_str(", ")?;
+            }
+            item.fmt(f)?;
+        }
+        f.write_str("]")
+    }
+}
+
+// serde impls
+#[cfg(feature = "serde")]
+extern crate serde;
+#[cfg(all(feature = "serde", test))]
+extern crate serde_json;
+#[cfg(feature = "serde")]
+use serde::{de::SeqAccess, de::Visitor, Deserialize, Deserializer, Serialize, Serializer};
+
+#[cfg(feature = "serde")]
+impl<T: Serialize, A: Allocator> Serialize for LinkedList<T, A> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeSeq;
+        let mut seq = serializer.serialize_seq(Some(self.len()))?;
+        for e in self.iter() {
+            seq.serialize_element(e)?;
+        }
+        seq.end()
+    }
+}
+#[cfg(feature = "serde")]
+struct LinkedListVisitor<T> {
+    marker: PhantomData<T>,
+}
+#[cfg(feature = "serde")]
+impl<'de, T: Deserialize<'de>> Visitor<'de> for LinkedListVisitor<T> {
+    type Value = LinkedList<T>;
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "a sequence")
+    }
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut list = match seq.size_hint() {
+            Some(hint) => LinkedList::with_capacity(hint),
+            None => LinkedList::new(),
+        };
+        while let Some(next) = seq.next_element()? {
+            list.push_back(next);
+        }
+        Ok(list)
+    }
+}
+#[cfg(feature = "serde")]
+struct LinkedListInPlaceVisitor<'a, T: 'a> {
+    list: &'a mut LinkedList<T>,
+}
+#[cfg(feature = "serde")]
+impl<'a, 'de, T: Deserialize<'de>> Visitor<'de> for LinkedListInPlaceVisitor<'a, T> {
+    type Value = ();
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "a sequence")
+    }
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<(), A::Error> {
+        // clearing keeps the nodes around, so they are reused below
+        self.list.clear();
+        if let Some(hint) = seq.size_hint() {
+            self.list.reserve(hint);
+        }
+        while let Some(next) = seq.next_element()? {
+            self.list.push_back(next);
+        }
+        Ok(())
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for LinkedList<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_seq(LinkedListVisitor {
+            marker: PhantomData,
+        })
+    }
+    fn deserialize_in_place<D: Deserializer<'de>>(
+        deserializer: D,
+        place: &mut Self,
+    ) -> Result<(), D::Error> {
+        deserializer.deserialize_seq(LinkedListInPlaceVisitor { list: place })
+    }
+}
+
+#[cfg(all(feature = "serde", test))]
+mod serde_test {
+    use super::*;
+    use rand::prelude::*;
+    #[test]
+    fn serialize() {
+        let mut list: LinkedList<u32> = LinkedList::new();
+        list.set_chunk_size(328);
+        for _ in 0..1028 {
+            list.push_back(random());
+        }
+
+        let json = serde_json::to_string(&list).unwrap();
+        println!("{}", &json);
+        let list2: LinkedList<u32> = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(list, list2);
+    }
+    #[test]
+    fn deserialize_in_place() {
+        let mut list: LinkedList<u32> = LinkedList::new();
+
+        let mut deserializer = serde_json::Deserializer::from_str("[1, 2, 3, 4, 5, 6]");
+        Deserialize::deserialize_in_place(&mut deserializer, &mut list).unwrap();
+        assert_eq!(list, vec![1, 2, 3, 4, 5, 6]);
+        let capacity = list.capacity();
+        let allocations = list.number_of_allocations();
+
+        let mut deserializer = serde_json::Deserializer::from_str("[7, 8]");
+        Deserialize::deserialize_in_place(&mut deserializer, &mut list).unwrap();
+        assert_eq!(list, vec![7, 8]);
+        assert_eq!(capacity, list.capacity());
+        assert_eq!(allocations, list.number_of_allocations());
+    }
+    #[test]
+    fn serialize_chunk_size() {
+        let mut list: LinkedList<u32> = LinkedList::new();
+        list.set_chunk_size(328);
+        for _ in 0..1028 {
+            list.push_back(random());
+        }
+
+        let mut json = Vec::new();
+        serde_chunk_size::serialize(&list, &mut serde_json::Serializer::new(&mut json)).unwrap();
+        let mut deserializer = serde_json::Deserializer::from_slice(&json);
+        let list2: LinkedList<u32> = serde_chunk_size::deserialize(&mut deserializer).unwrap();
+
+        assert_eq!(list, list2);
+        assert_eq!(328, list2.chunk_size());
+
+        // the fields may also come in the other order
+        let json = r#"{"items":[1,2,3],"chunk_size":5}"#;
+        let mut deserializer = serde_json::Deserializer::from_str(json);
+        let list3: LinkedList<u32> = serde_chunk_size::deserialize(&mut deserializer).unwrap();
+        assert_eq!(list3, vec![1, 2, 3]);
+        assert_eq!(5, list3.chunk_size());
+
+        let json = r#"{"chunk_size":0,"items":[]}"#;
+        let mut deserializer = serde_json::Deserializer::from_str(json);
+        assert!(serde_chunk_size::deserialize::<u32, _>(&mut deserializer).is_err());
+
+        // the default is still a plain sequence
+        assert_eq!("[1,2,3]", serde_json::to_string(&list3).unwrap());
+    }
+}
+
+// rayon impls
+#[cfg(feature = "rayon")]
+extern crate rayon;
+#[cfg(feature = "rayon")]
+use rayon::iter::IntoParallelIterator;
+
+/// Parallel iteration over the list.
+#[cfg(feature = "rayon")]
+impl<T: Send> IntoParallelIterator for LinkedList<T> {
+    type Iter = rayon::vec::IntoIter<T>;
+    type Item = T;
+    fn into_par_iter(self) -> Self::Iter {
+        self.into_iter().collect::<Vec<T>>().into_par_iter()
+    }
+}
+/// Parallel iteration over references to the elements.
+#[cfg(feature = "rayon")]
+impl<'a, T: Sync> IntoParallelIterator for &'a LinkedList<T> {
+    type Iter = rayon::vec::IntoIter<&'a T>;
+    type Item = &'a T;
+    fn into_par_iter(self) -> Self::Iter {
+        self.iter().collect::<Vec<&'a T>>().into_par_iter()
+    }
+}
+/// Parallel iteration over mutable references to the elements.
+#[cfg(feature = "rayon")]
+impl<'a, T: Send> IntoParallelIterator for &'a mut LinkedList<T> {
+    type Iter = rayon::vec::IntoIter<&'a mut T>;
+    type Item = &'a mut T;
+    fn into_par_iter(self) -> Self::Iter {
+        self.iter_mut().collect::<Vec<&'a mut T>>().into_par_iter()
+    }
+}
+
+#[cfg(all(feature = "rayon", test))]
+mod rayon_test {
+    use super::*;
+    use rayon::prelude::*;
+    #[test]
+    fn par_iter_sum() {
+        let list: LinkedList<u64> = (0..100_000).collect();
+        let sequential: u64 = list.iter().map(|i| i * i).sum();
+        let parallel: u64 = list.par_iter().map(|i| i * i).sum();
+        assert_eq!(sequential, parallel);
+    }
+    #[test]
+    fn par_iter_mut_and_into_par_iter() {
+        let mut list: LinkedList<u64> = (0..1000).collect();
+        list.par_iter_mut().for_each(|i| *i *= 2);
+        assert_eq!(list, (0..1000).map(|i| 2 * i).collect::<Vec<u64>>());
+
+        let vec: Vec<u64> = list.into_par_iter().map(|i| i + 1).collect();
+        assert_eq!(vec, (0..1000).map(|i| 2 * i + 1).collect::<Vec<u64>>());
+    }
+}
+
+#[cfg(all(feature = "allocator_api", test))]
+mod allocator_test {
+    use super::*;
+    use std::alloc::{AllocError, System};
+    use std::cell::Cell;
+    use std::ptr::NonNull;
+
+    /// Counts the allocations and deallocations made through it.
+    #[derive(Default)]
+    struct Counting {
+        allocs: Cell<usize>,
+        deallocs: Cell<usize>,
+        bytes: Cell<usize>,
+    }
+    unsafe impl Allocator for &Counting {
+        fn allocate(&self, layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
+            self.allocs.set(self.allocs.get() + 1);
+            self.bytes.set(self.bytes.get() + layout.size());
+            System.allocate(layout)
+        }
+        unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+            self.deallocs.set(self.deallocs.get() + 1);
+            self.bytes.set(self.bytes.get() - layout.size());
+            System.deallocate(ptr, layout)
+        }
+    }
+
+    #[test]
+    fn counting_allocator_balances() {
+        let counting = Counting::default();
+        {
+            let mut list: LinkedList<String, &Counting> =
+                LinkedList::with_chunk_size_in(4, &counting);
+            assert_eq!(0, counting.allocs.get());
+            list.extend((0..10).map(|i| i.to_string()));
+            assert_eq!(list.number_of_allocations(), counting.allocs.get());
+            assert_eq!(list.allocated_bytes(), counting.bytes.get());
+
+            let mut other = LinkedList::with_capacity_in(3, &counting);
+            other.push_back("x".to_string());
+            // both lists use the same allocator
+            unsafe { list.append_unchecked(&mut other) };
+            assert_eq!(list.number_of_allocations(), counting.allocs.get());
+
+            let clone = list.clone();
+            assert_eq!(clone, list);
+            drop(clone);
+
+            list.truncate(2);
+            list.shrink_to_fit();
+            list.compact();
+            assert_eq!(list, vec!["0", "1"]);
+            assert_eq!(list.allocated_bytes(), counting.bytes.get());
+
+            let mut iter = list.into_iter();
+            assert_eq!(Some("0".to_string()), iter.next());
+        }
+        assert!(counting.allocs.get() > 0);
+        assert_eq!(counting.allocs.get(), counting.deallocs.get());
+        assert_eq!(0, counting.bytes.get());
+    }
+
+    #[test]
+    fn distinct_allocators_balance() {
+        let counting_a = Counting::default();
+        let counting_b = Counting::default();
+        {
+            let mut list_a: LinkedList<String, &Counting> =
+                LinkedList::with_chunk_size_in(4, &counting_a);
+            let mut list_b: LinkedList<String, &Counting> =
+                LinkedList::with_chunk_size_in(3, &counting_b);
+            list_a.extend((0..5).map(|i| i.to_string()));
+            list_b.extend((5..12).map(|i| i.to_string()));
+
+            list_a.append_elements_only(&mut list_b);
+            assert_eq!(list_a, (0..12).map(|i| i.to_string()).collect::<Vec<_>>());
+            assert!(list_b.is_empty());
+            assert_eq!(list_a.allocated_bytes(), counting_a.bytes.get());
+            assert_eq!(list_b.allocated_bytes(), counting_b.bytes.get());
+
+            list_b.extend(list_a.pop_front_n(2));
+            assert_eq!(list_b, vec!["0", "1"]);
+            assert_eq!(list_a.allocated_bytes(), counting_a.bytes.get());
+            assert_eq!(list_b.allocated_bytes(), counting_b.bytes.get());
+
+            list_a.retain(|s| s.len() == 1);
+            list_b.compact();
+        }
+        assert!(counting_a.allocs.get() > 0);
+        assert!(counting_b.allocs.get() > 0);
+        assert_eq!(counting_a.allocs.get(), counting_a.deallocs.get());
+        assert_eq!(counting_b.allocs.get(), counting_b.deallocs.get());
+        assert_eq!(0, counting_a.bytes.get());
+        assert_eq!(0, counting_b.bytes.get());
+    }
+
+    /// Returns twice the requested memory, like an allocator that rounds up to size
+    /// classes.
+    struct OverAllocating;
+    fn doubled(layout: Layout) -> Layout {
+        Layout::from_size_align(layout.size() * 2, layout.align()).unwrap()
+    }
+    unsafe impl Allocator for OverAllocating {
+        fn allocate(&self, layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
+            System.allocate(doubled(layout))
+        }
+        unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+            System.deallocate(ptr, doubled(layout))
+        }
+    }
+
+    #[test]
+    fn capacity_is_exact_with_over_allocation() {
+        for &cap in &[1, 2, 3, 7, 64, 100, 293, 1000] {
+            let mut list: LinkedList<u32, OverAllocating> =
+                LinkedList::with_capacity_in(cap, OverAllocating);
+            assert_eq!(cap, list.capacity());
+            list.extend(0..cap as u32);
+            assert_eq!(cap, list.capacity());
+            assert_eq!(1, list.number_of_allocations());
+
+            let mut list: LinkedList<u32, OverAllocating> =
+                LinkedList::with_chunk_size_in(cap, OverAllocating);
+            list.push_back(0);
+            assert_eq!(cap, list.capacity());
+            list.reserve(cap);
+            assert_eq!(2 * cap, list.capacity());
+        }
+    }
+
+    #[test]
+    fn reserve_is_exact_with_over_allocation() {
+        let mut list: LinkedList<u32, OverAllocating> =
+            LinkedList::with_capacity_in(5, OverAllocating);
+        list.push_back(3);
+        list.reserve(84);
+        assert_eq!(85, list.capacity());
+        assert_eq!(2, list.number_of_allocations());
+
+        // small reservations are rounded up to the chunk size
+        let mut list: LinkedList<u32, OverAllocating> =
+            LinkedList::with_capacity_and_chunk_size_in(5, 16, OverAllocating);
+        list.extend(0..5);
+        list.reserve(1);
+        assert_eq!(21, list.capacity());
+        list.reserve(16);
+        assert_eq!(21, list.capacity());
+        list.reserve(17);
+        assert_eq!(37, list.capacity());
+    }
+
+    #[test]
+    fn map_in_place_keeps_allocator() {
+        let counting = Counting::default();
+        {
+            let mut list: LinkedList<u32, &Counting> = LinkedList::new_in(&counting);
+            list.extend(0..100);
+            let list = list.map_in_place(|i| i as i32);
+            let list = list.map_in_place(|i| i as u64);
+            assert_eq!(list, (0..100).collect::<Vec<u64>>());
+            assert_eq!(list.allocated_bytes(), counting.bytes.get());
+        }
+        assert_eq!(counting.allocs.get(), counting.deallocs.get());
+        assert_eq!(0, counting.bytes.get());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::prelude::*;
+    use std::fmt::Write;
+    #[test]
+    fn retain() {
+        let mut list: LinkedList<usize> = LinkedList::new();
+        for i in 0..16 {
+            list.push_back(i);
+        }
+
+        let mut rng = thread_rng();
+
+        let mut mask = [false; 16];
+        for val in mask.iter_mut() {
+            *val = rng.gen();
+        }
+
+        list.retain_map(|i| if mask[i] { Some(i + 1) } else { None });
+
+        let nums: Vec<usize> = (0..16).filter(|&i| mask[i]).map(|i| i + 1).collect();
+
+        println!("{:?}", mask);
+        for (a, b) in list.into_iter().zip(nums) {
+            assert_eq!(a, b);
+        }
+    }
+
+    #[test]
+    fn iter_collect_compare() {
+        let mut list = LinkedList::new();
+        for i in 0..64usize {
+            list.push_back(i);
+        }
+        let list2: LinkedList<u32> = list.iter().map(|&i| i as u32).collect();
+        let vec: Vec<u32> = list.into_iter().map(|i| i as u32).collect();
+
+        assert_eq!(list2, vec);
+    }
+    #[test]
+    fn debug_print_list() {
+        let mut output = String::new();
+        let mut list = LinkedList::new();
+        list.push_back(1);
+        list.push_back(2);
+        list.push_back(3);
+        list.push_back(4);
+        write!(output, "{:?}", list).unwrap();
+        assert_eq!(output, "[1, 2, 3, 4]");
+    }
+    #[test]
+    fn debug_print_iter() {
+        let mut output = String::new();
+        let mut list = LinkedList::new();
+        list.push_back(1);
+        list.push_back(2);
+        list.push_back(3);
+        list.push_back(4);
+
+        let mut iter = list.iter();
+
+        write!(output, "{:?}", iter).unwrap();
+        assert_eq!(output, "LinkedList::Iter[1, 2, 3, 4]");
+        output.clear();
+
+        let _ = iter.next();
+        let _ = iter.next_back();
+
+        write!(output, "{:?}", iter).unwrap();
+        assert_eq!(output, "LinkedList::Iter[2, 3]");
+        output.clear();
+    }
+    #[test]
+    fn debug_print_iter_mut() {
+        let mut output = String::new();
+        let mut list = LinkedList::new();
+        list.push_back(1);
+        list.push_back(2);
+        list.push_back(3);
+        list.push_back(4);
+
+        let mut iter = list.iter_mut();
+
+        write!(output, "{:?}", iter).unwrap();
+        assert_eq!(output, "LinkedList::IterMut[1, 2, 3, 4]");
+        output.clear();
+
+        let _ = iter.next();
+        let _ = iter.next_back();
+
+        write!(output, "{:?}", iter).unwrap();
+        assert_eq!(output, "LinkedList::IterMut[2, 3]");
+        output.clear();
+    }
+    #[test]
+    fn debug_print_into_iter() {
+        let mut output = String::new();
+        let mut list = LinkedList::new();
+        list.push_back(1);
+        list.push_back(2);
+        list.push_back(3);
+        list.push_back(4);
+
+        let mut iter = list.into_iter();
+
+        write!(output, "{:?}", iter).unwrap();
+        assert_eq!(output, "LinkedList::IntoIter[1, 2, 3, 4]");
+        output.clear();
+
+        let _ = iter.next();
+        let _ = iter.next_back();
+
+        write!(output, "{:?}", iter).unwrap();
+        assert_eq!(output, "LinkedList::IntoIter[2, 3]");
+        output.clear();
+    }
+    #[test]
+    #[allow(clippy::drop_non_drop)]
+    fn iter_mut_several_mut_ref() {
+        let mut list = LinkedList::new();
+        list.push_back(1);
+        list.push_back(2);
+        list.push_back(3);
+        list.push_back(4);
+
+        {
+            let mut iter_mut = list.iter_mut();
+            let ref1 = iter_mut.next().unwrap();
+            let ref2 = iter_mut.next().unwrap();
+            drop(iter_mut);
+            *ref1 = 6;
+            *ref2 = 7;
+        }
+
+        assert_eq!(list, vec![6, 7, 3, 4]);
+    }
+    #[test]
+    fn pop_unlinks_removed_node() {
+        let mut list: LinkedList<String> = LinkedList::new();
+        list.push_back("a".to_string());
+        list.push_back("b".to_string());
+        list.push_back("c".to_string());
+        list.push_back("d".to_string());
+
+        assert_eq!(list.pop_back(), Some("d".to_string()));
+        assert_eq!(list.pop_front(), Some("a".to_string()));
+
+        // the new ends must not link to the popped nodes
+        assert!(!list.cursor_mut_back().unwrap().go_next());
+        assert!(!list.cursor_mut_front().unwrap().go_prev());
+
+        // dropping the list must not touch the popped nodes
+        drop(list);
+    }
+    #[test]
+    fn peek_after_pop() {
+        let mut list: LinkedList<String> = LinkedList::new();
+        list.push_back("a".to_string());
+        list.push_back("b".to_string());
+        list.push_back("c".to_string());
+        list.push_back("d".to_string());
+
+        assert_eq!(list.pop_back(), Some("d".to_string()));
+        assert_eq!(list.pop_front(), Some("a".to_string()));
+
+        {
+            let mut cursor = list.cursor_mut_back().unwrap();
+            assert_eq!(None, cursor.peek_next());
+            assert_eq!(Some(&"b".to_string()), cursor.peek_prev());
+            assert!(cursor.go_prev());
+            assert_eq!(None, cursor.peek_prev());
+            assert_eq!(Some(&"c".to_string()), cursor.peek_next());
+        }
+
+        // dropping the list must not touch the popped nodes
+        drop(list);
+    }
+    #[test]
+    fn try_retain_map_error() {
+        for stop in 0..8 {
+            let mut list: LinkedList<usize> = (0..8).collect();
+            let capacity = list.capacity();
+
+            // remove multiples of three and add 100 to the rest until we reach stop
+            let result = list.try_retain_map(|i| {
+                if i == stop {
+                    Err(i)
+                } else if i % 3 == 0 {
+                    Ok(None)
+                } else {
+                    Ok(Some(i + 100))
+                }
+            });
+            assert_eq!(result, Err(stop));
+            assert_eq!(capacity, list.capacity());
+
+            let expected: Vec<usize> = (0..8)
+                .filter(|&i| i > stop || (i < stop && i % 3 != 0))
+                .map(|i| if i < stop { i + 100 } else { i })
+                .collect();
+            assert_eq!(list, expected);
+            assert_eq!(expected.len(), list.len());
+
+            // every element is either in the list, was removed, or was returned
+            let mut all: Vec<usize> = list
+                .iter()
+                .map(|&i| if i >= 100 { i - 100 } else { i })
+                .chain((0..stop).filter(|i| i % 3 == 0))
+                .chain(Some(stop))
+                .collect();
+            all.sort();
+            assert_eq!(all, (0..8).collect::<Vec<usize>>());
+
+            // check the links in both directions
+            let backwards: Vec<usize> = list.iter().rev().cloned().collect();
+            assert_eq!(
+                backwards,
+                expected.into_iter().rev().collect::<Vec<usize>>()
+            );
+        }
+    }
+    #[test]
+    fn retain_map_unlinks_tail() {
+        let mut list: LinkedList<String> = LinkedList::new();
+        list.extend(vec!["a".to_string(), "b".to_string(), "c".to_string()]);
+        list.retain(|s| s != "c");
+        assert!(list.cursor_ref_back().unwrap().is_back());
+        list.push_back("d".to_string());
+        assert_eq!(list, vec!["a", "b", "d"]);
+    }
+    #[test]
+    fn map_in_place_reuses_nodes() {
+        let mut list: LinkedList<u32> = LinkedList::new();
+        list.set_chunk_size(5);
+        list.extend(0..12);
+        list.pop_front();
+        let capacity = list.capacity();
+        let allocations: Vec<usize> = list.allocations.iter().map(|&(p, _)| p as usize).collect();
+
+        let mut list: LinkedList<i32> = list.map_in_place(|i| i as i32 - 5);
+        assert_eq!(list, (-4..7).collect::<Vec<i32>>());
+        assert_eq!(capacity, list.capacity());
+        assert_eq!(5, list.chunk_size());
+        let allocations2: Vec<usize> = list.allocations.iter().map(|&(p, _)| p as usize).collect();
+        assert_eq!(allocations, allocations2);
+
+        // the free node is still usable
+        list.push_back(7);
+        assert_eq!(capacity, list.capacity());
+        assert_eq!(list, (-4..8).collect::<Vec<i32>>());
+    }
+    #[test]
+    fn map_in_place_drops() {
+        use std::rc::Rc;
+        struct A(Rc<()>);
+        #[allow(dead_code)]
+        struct B(Rc<()>);
+
+        let counter = Rc::new(());
+        let list: LinkedList<A> = (0..10).map(|_| A(counter.clone())).collect();
+        assert_eq!(11, Rc::strong_count(&counter));
+
+        // same layout
+        let list: LinkedList<B> = list.map_in_place(|A(rc)| B(rc));
+        assert_eq!(11, Rc::strong_count(&counter));
+        // different layout
+        let list: LinkedList<(B, String)> = list.map_in_place(|b| (b, String::new()));
+        assert_eq!(11, Rc::strong_count(&counter));
+        // drop some of the values in the closure
+        let mut i = 0;
+        let list: LinkedList<Option<B>> = list.map_in_place(|(b, _)| {
+            i += 1;
+            if i % 2 == 0 {
+                None
+            } else {
+                Some(b)
+            }
+        });
+        assert_eq!(10, list.len());
+        assert_eq!(6, Rc::strong_count(&counter));
+
+        drop(list);
+        assert_eq!(1, Rc::strong_count(&counter));
+    }
+    #[test]
+    fn map_in_place_fallback() {
+        let mut list: LinkedList<u32> = LinkedList::new();
+        list.set_chunk_size(3);
+        list.extend(&[1, 2, 3, 4]);
+        let list: LinkedList<String> = list.map_in_place(|i| i.to_string());
+        assert_eq!(list, vec!["1", "2", "3", "4"]);
+        assert_eq!(3, list.chunk_size());
+    }
+    #[test]
+    fn dedup_like_vec() {
+        let mut rng = thread_rng();
+        let inputs: Vec<Vec<u8>> = vec![
+            vec![],
+            vec![1],
+            vec![3; 10],
+            vec![1, 1, 2, 3, 3, 3, 4, 1, 1],
+            (0..64).map(|_| rng.gen_range(0, 3)).collect(),
+        ];
+        for input in inputs {
+            let mut list: LinkedList<u8> = input.iter().cloned().collect();
+            let mut vec = input.clone();
+            let capacity = list.capacity();
+
+            list.dedup();
+            vec.dedup();
+
+            assert_eq!(list, vec);
+            assert_eq!(vec.len(), list.len());
+            assert_eq!(capacity, list.capacity());
+            assert_eq!(vec.last(), list.back());
+            let backwards: Vec<u8> = list.iter().rev().cloned().collect();
+            vec.reverse();
+            assert_eq!(backwards, vec);
+        }
+    }
+    /// An iterator with a lower bound smaller than its actual length.
+    struct LooseLowerBound(usize);
+    impl Iterator for LooseLowerBound {
+        type Item = usize;
+        fn next(&mut self) -> Option<usize> {
+            if self.0 == 0 {
+                None
+            } else {
+                self.0 -= 1;
+                Some(self.0)
+            }
+        }
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            (self.0 * 3 / 4, Some(self.0))
+        }
+    }
+    #[test]
+    fn reserve_upper_size_hint() {
+        let list: LinkedList<usize> = LooseLowerBound(1000).collect();
+        assert_eq!(1000, list.len());
+        assert_eq!(1000, list.capacity());
+        assert_eq!(1, list.allocations.len());
+
+        let mut list: LinkedList<usize> = LinkedList::new();
+        list.push_back(0);
+        list.extend(LooseLowerBound(1000));
+        assert_eq!(1001, list.len());
+        assert_eq!(1001, list.capacity());
+        assert_eq!(2, list.allocations.len());
+
+        // loose upper bounds are not reserved
+        let list: LinkedList<usize> = (0..1000).filter(|&i| i < 10).collect();
+        assert_eq!(10, list.len());
+        assert_eq!(list.chunk_size(), list.capacity());
+    }
+    #[test]
+    fn allocation_count_after_append() {
+        let mut list: LinkedList<u32> = LinkedList::with_chunk_size(4);
+        let mut chunks = 0;
+        for i in 0..6 {
+            let mut other: LinkedList<u32> = LinkedList::with_chunk_size(4);
+            // pushing one at a time allocates i + 1 chunks
+            for j in 0..i * 4 + 1 {
+                other.push_back(j);
+            }
+            chunks += other.number_of_allocations();
+            list.append(&mut other);
+            assert_eq!(0, other.number_of_allocations());
+            assert_eq!(0, other.allocated_bytes());
+        }
+        assert_eq!(1 + 2 + 3 + 4 + 5 + 6, chunks);
+        assert_eq!(chunks, list.number_of_allocations());
+        assert_eq!(
+            list.capacity() * mem::size_of::<LinkedNode<u32>>(),
+            list.allocated_bytes()
+        );
+    }
+    #[test]
+    fn compact_fragmented() {
+        let mut list: LinkedList<String> = LinkedList::with_chunk_size(3);
+        for i in 0..5 {
+            let mut other: LinkedList<String> = LinkedList::with_chunk_size(2);
+            for j in 0..i {
+                other.push_back(format!("{}-{}", i, j));
+            }
+            list.append(&mut other);
+            list.push_front(format!("{}", i));
+        }
+        list.retain(|s| !s.ends_with('1'));
+        let expected: Vec<String> = list.iter().cloned().collect();
+        assert!(list.number_of_allocations() > 1);
+
+        list.compact();
+
+        assert_eq!(1, list.number_of_allocations());
+        assert_eq!(expected.len(), list.capacity());
+        assert_eq!(3, list.chunk_size());
+        assert_eq!(list, expected);
+        let backwards: Vec<String> = list.iter().rev().cloned().collect();
+        assert_eq!(
+            backwards,
+            expected.iter().rev().cloned().collect::<Vec<String>>()
+        );
+
+        // the list keeps working afterwards
+        list.push_back("end".to_string());
+        assert_eq!(expected.len() + 3, list.capacity());
+        assert_eq!(Some(&"end".to_string()), list.back());
+
+        list.clear();
+        list.compact();
+        assert_eq!(0, list.number_of_allocations());
+        assert_eq!(0, list.capacity());
+    }
+    #[test]
+    fn swap_next_bubble() {
+        for len in 1..6 {
+            let mut list: LinkedList<usize> = (0..len).collect();
+            let mut vec: Vec<usize> = (0..len).collect();
+            {
+                let mut cursor = list.cursor_mut_front().unwrap();
+                for i in 0..len - 1 {
+                    assert!(cursor.swap_next());
+                    assert_eq!(i + 1, cursor.index());
+                    assert_eq!(&0, cursor.get_ref());
+                    vec.swap(i, i + 1);
+                }
+                assert!(!cursor.swap_next());
+                assert!(cursor.is_back());
+            }
+            assert_eq!(list, vec);
+            assert_eq!(len, list.len());
+            assert_eq!(Some(&0), list.back());
+            // the links must be consistent in both directions
+            let backwards: Vec<usize> = list.iter().rev().cloned().collect();
+            vec.reverse();
+            assert_eq!(backwards, vec);
+        }
+    }
+    #[test]
+    fn debug_print_iter_rev() {
+        let mut output = String::new();
+        let mut list = LinkedList::new();
+        list.push_back(1);
+        list.push_back(2);
+        list.push_back(3);
+        list.push_back(4);
+
+        let mut iter = list.iter_rev();
+
+        write!(output, "{:?}", iter).unwrap();
+        assert_eq!(output, "LinkedList::Iter[4, 3, 2, 1]");
+        output.clear();
+
+        assert_eq!(Some(&4), iter.next());
+        assert_eq!(Some(&1), iter.next_back());
+
+        write!(output, "{:?}", iter).unwrap();
+        assert_eq!(output, "LinkedList::Iter[3, 2]");
+        output.clear();
+
+        let mut iter_mut = list.iter_mut_rev();
+
+        write!(output, "{:?}", iter_mut).unwrap();
+        assert_eq!(output, "LinkedList::IterMut[4, 3, 2, 1]");
+        output.clear();
+
+        assert_eq!(Some(&mut 4), iter_mut.next());
+        assert_eq!(Some(&mut 1), iter_mut.next_back());
+        assert_eq!(2, iter_mut.len());
+
+        write!(output, "{:?}", iter_mut).unwrap();
+        assert_eq!(output, "LinkedList::IterMut[3, 2]");
+        output.clear();
+    }
+    #[test]
+    fn iter_rev_order() {
+        let list: LinkedList<u32> = (0..10).collect();
+        let rev: Vec<u32> = list.iter_rev().cloned().collect();
+        assert_eq!(rev, (0..10).rev().collect::<Vec<u32>>());
+        assert_eq!(Some(&0), list.iter_rev().last());
+        assert_eq!(10, list.iter_rev().count());
+
+        let forward: Vec<u32> = list.iter_rev().rev().cloned().collect();
+        assert_eq!(forward, (0..10).collect::<Vec<u32>>());
+    }
+    #[test]
+    fn into_iter_into_list() {
+        use std::rc::Rc;
+        let counter = Rc::new(());
+        let list: LinkedList<Rc<()>> = (0..10).map(|_| counter.clone()).collect();
+        let capacity = list.capacity();
+
+        let mut iter = list.into_iter();
+        drop(iter.next());
+        drop(iter.next_back());
+        drop(iter.next());
+        assert_eq!(8, Rc::strong_count(&counter));
+
+        let mut list = iter.into_list();
+        assert_eq!(7, list.len());
+        assert_eq!(capacity, list.capacity());
+        assert_eq!(8, Rc::strong_count(&counter));
+
+        // the nodes of the consumed elements can be reused
+        for _ in 0..3 {
+            list.push_front(counter.clone());
+        }
+        assert_eq!(capacity, list.capacity());
+        assert_eq!(11, Rc::strong_count(&counter));
+
+        // last must not drop the last element twice
+        assert!(list.into_iter().last().is_some());
+        assert_eq!(1, Rc::strong_count(&counter));
+    }
+    #[test]
+    fn cursor_mut_as_cursor_ref() {
+        let mut list: LinkedList<u32> = (0..5).collect();
+        let mut cursor = list.cursor_mut_at(2).unwrap();
+        {
+            let cursor_ref = cursor.as_cursor_ref();
+            assert!(cursor.ptr_eq(cursor_ref));
+            assert_eq!(&2, cursor_ref.get());
+            assert_eq!(2, cursor_ref.index());
+            assert!(!cursor.ptr_eq(cursor_ref.next().unwrap()));
+            assert!(cursor.ptr_eq(cursor_ref.next().unwrap().prev().unwrap()));
+        }
+        *cursor.get() = 20;
+        cursor.go_prev();
+        let prev = cursor.as_cursor_ref();
+        assert_eq!(&1, prev.get());
+        assert_eq!(&20, prev.next().unwrap().get());
+        assert!(cursor.ptr_eq(prev));
+    }
+    #[test]
+    fn swap_like_vec() {
+        let pairs = [
+            (1, 4),
+            (2, 3),
+            (3, 2),
+            (0, 5),
+            (5, 0),
+            (0, 1),
+            (4, 5),
+            (3, 3),
+        ];
+        let mut list: LinkedList<u32> = (0..6).collect();
+        let mut vec: Vec<u32> = (0..6).collect();
+        for &(i, j) in &pairs {
+            list.swap(i, j);
+            vec.swap(i, j);
+            assert_eq!(list, vec);
+            // check the links in the other direction too
+            let rev: Vec<u32> = list.iter_rev().cloned().collect();
+            assert_eq!(rev, vec.iter().rev().cloned().collect::<Vec<u32>>());
+        }
+        assert_eq!(Some(&vec[0]), list.front());
+        assert_eq!(Some(&vec[5]), list.back());
+
+        let mut single: LinkedList<u32> = LinkedList::new();
+        single.push_back(1);
+        single.swap(0, 0);
+        assert_eq!(single, vec![1]);
+    }
+    #[test]
+    #[should_panic]
+    fn swap_out_of_bounds() {
+        let mut list: LinkedList<u32> = (0..3).collect();
+        list.swap(0, 3);
+    }
+    #[test]
+    fn partition_even_odd() {
+        use std::rc::Rc;
+        let counter = Rc::new(());
+        let list: LinkedList<(u32, Rc<()>)> = (0..20).map(|i| (i, counter.clone())).collect();
+        let capacity = list.capacity();
+
+        let (even, odd) = list.partition(|&(i, _)| i % 2 == 0);
+        let even_values: Vec<u32> = even.iter().map(|&(i, _)| i).collect();
+        let odd_values: Vec<u32> = odd.iter().map(|&(i, _)| i).collect();
+        assert_eq!(
+            even_values,
+            (0..20).filter(|i| i % 2 == 0).collect::<Vec<u32>>()
+        );
+        assert_eq!(
+            odd_values,
+            (0..20).filter(|i| i % 2 == 1).collect::<Vec<u32>>()
+        );
+        assert_eq!(capacity, even.capacity());
+        assert_eq!(21, Rc::strong_count(&counter));
+
+        drop(even);
+        assert_eq!(11, Rc::strong_count(&counter));
+        drop(odd);
+        assert_eq!(1, Rc::strong_count(&counter));
+
+        let (all, none) = (0..5).collect::<LinkedList<u32>>().partition(|_| true);
+        assert_eq!(all, vec![0, 1, 2, 3, 4]);
+        assert!(none.is_empty());
+        assert_eq!(0, none.capacity());
+    }
+    #[test]
+    fn concat_five_lists() {
+        let mut last = LinkedList::with_chunk_size(2);
+        last.extend(40..45);
+        let lists: Vec<LinkedList<u32>> = vec![
+            (0..3).collect(),
+            LinkedList::with_capacity(10),
+            (3..40).collect(),
+            LinkedList::new(),
+            last,
+        ];
+
+        let capacity: usize = lists.iter().map(|list| list.capacity()).sum();
+        let allocations: usize = lists.iter().map(|list| list.number_of_allocations()).sum();
+
+        let list = LinkedList::concat(lists);
+        assert_eq!(list, (0..45).collect::<Vec<u32>>());
+        assert_eq!(45, list.len());
+        assert_eq!(capacity, list.capacity());
+        assert_eq!(allocations, list.number_of_allocations());
+        assert_eq!(list.iter_rev().count(), 45);
+        assert_eq!(Some(&44), list.iter_rev().next());
+    }
+    #[test]
+    fn std_list_round_trip() {
+        let vec: Vec<u32> = (0..100).collect();
+        let std_list: std::collections::LinkedList<u32> = vec.iter().cloned().collect();
+
+        let list = LinkedList::from(std_list);
+        assert_eq!(list, vec);
+        assert_eq!(100, list.capacity());
+        assert_eq!(1, list.number_of_allocations());
+
+        let std_list: std::collections::LinkedList<u32> = list.into();
+        assert!(std_list.iter().eq(vec.iter()));
+
+        let empty = LinkedList::from(std::collections::LinkedList::<u32>::new());
+        assert!(empty.is_empty());
+        assert!(std::collections::LinkedList::from(empty).is_empty());
+    }
+    #[test]
+    fn binary_search_like_vec() {
+        let vec: Vec<u32> = (0..50).map(|i| i * 3).collect();
+        let list: LinkedList<u32> = vec.iter().cloned().collect();
+        for x in 0..160 {
+            assert_eq!(vec.binary_search(&x), list.binary_search(&x));
+        }
+
+        let single: LinkedList<u32> = Some(5).into_iter().collect();
+        assert_eq!(Ok(0), single.binary_search(&5));
+        assert_eq!(Err(0), single.binary_search(&4));
+        assert_eq!(Err(1), single.binary_search(&6));
+
+        let empty: LinkedList<u32> = LinkedList::new();
+        assert_eq!(Err(0), empty.binary_search(&1));
+        assert_eq!(Err(0), empty.binary_search_by(|_| unreachable!()));
+    }
+    #[test]
+    fn position_and_rposition() {
+        let list: LinkedList<u32> = vec![5, 1, 7, 1, 9].into_iter().collect();
+        assert_eq!(Some(0), list.position(|&i| i == 5));
+        assert_eq!(Some(0), list.rposition(|&i| i == 5));
+        assert_eq!(Some(4), list.position(|&i| i == 9));
+        assert_eq!(Some(4), list.rposition(|&i| i == 9));
+        assert_eq!(Some(1), list.position(|&i| i == 1));
+        assert_eq!(Some(3), list.rposition(|&i| i == 1));
+        assert_eq!(Some(2), list.position(|&i| i == 7));
+        assert_eq!(None, list.position(|&i| i == 2));
+        assert_eq!(None, list.rposition(|&i| i == 2));
+
+        // both stop at the first match
+        let mut visited = 0;
+        list.rposition(|&i| {
+            visited += 1;
+            i == 7
+        });
+        assert_eq!(3, visited);
+
+        let empty: LinkedList<u32> = LinkedList::new();
+        assert_eq!(None, empty.position(|_| true));
+        assert_eq!(None, empty.rposition(|_| true));
+    }
+    #[test]
+    fn cursor_insert_many() {
+        let mut list: LinkedList<u32> = vec![1, 2, 3].into_iter().collect();
+        {
+            let mut cursor = list.cursor_mut_at(1).unwrap();
+            cursor.insert_many(vec![10, 11, 12]);
+            assert_eq!(&2, cursor.get_ref());
+            assert_eq!(1, cursor.index());
+        }
+        assert_eq!(list, vec![1, 2, 10, 11, 12, 3]);
+        assert_eq!(6, list.len());
+
+        {
+            let mut cursor = list.cursor_mut_back().unwrap();
+            cursor.insert_many(20..23);
+            assert_eq!(&3, cursor.get_ref());
+            assert_eq!(5, cursor.index());
+        }
+        assert_eq!(list, vec![1, 2, 10, 11, 12, 3, 20, 21, 22]);
+        assert_eq!(Some(&22), list.back());
+        let rev: Vec<u32> = list.iter_rev().cloned().collect();
+        assert_eq!(rev, vec![22, 21, 20, 3, 12, 11, 10, 2, 1]);
+
+        let capacity = list.capacity();
+        list.cursor_mut_front().unwrap().insert_many(None);
+        assert_eq!(list, vec![1, 2, 10, 11, 12, 3, 20, 21, 22]);
+        assert_eq!(capacity, list.capacity());
+
+        // the size hint is used to reserve capacity in one allocation
+        let mut list: LinkedList<u32> = LinkedList::with_chunk_size(1);
+        list.push_back(0);
+        list.cursor_mut_front().unwrap().insert_many(1..100);
+        assert_eq!(list, (0..100).collect::<Vec<u32>>());
+        assert_eq!(2, list.number_of_allocations());
+    }
+    #[test]
+    fn iter_mut_split_at_halves() {
+        let mut list: LinkedList<u32> = (0..10).collect();
+        {
+            let (front, back) = list.iter_mut_split_at(4);
+            assert_eq!(4, front.len());
+            assert_eq!(6, back.len());
+            let front: Vec<&mut u32> = front.collect();
+            let back: Vec<&mut u32> = back.rev().collect();
+            for value in front {
+                *value += 100;
+            }
+            for value in back {
+                *value *= 2;
+            }
+        }
+        assert_eq!(list, vec![100, 101, 102, 103, 8, 10, 12, 14, 16, 18]);
+
+        for index in 0..=10 {
+            let (front, back) = list.iter_mut_split_at(index);
+            let front: Vec<u32> = front.map(|&mut v| v).collect();
+            let back: Vec<u32> = back.map(|&mut v| v).collect();
+            assert_eq!(index, front.len());
+            assert_eq!(10 - index, back.len());
+            assert_eq!(list, [front, back].concat());
+        }
+
+        let mut empty: LinkedList<u32> = LinkedList::new();
+        let (front, back) = empty.iter_mut_split_at(0);
+        assert_eq!(0, front.count() + back.count());
+    }
+    #[test]
+    fn clone_from_reuses_values() {
+        use std::cell::Cell;
+        thread_local!(static CLONES: Cell<usize> = const { Cell::new(0) });
+        thread_local!(static CLONE_FROMS: Cell<usize> = const { Cell::new(0) });
+        #[derive(Debug, PartialEq)]
+        struct Counted(u32);
+        impl Clone for Counted {
+            fn clone(&self) -> Self {
+                CLONES.with(|c| c.set(c.get() + 1));
+                Counted(self.0)
+            }
+            fn clone_from(&mut self, source: &Self) {
+                CLONE_FROMS.with(|c| c.set(c.get() + 1));
+                self.0 = source.0;
+            }
+        }
+        let counts = || (CLONES.with(Cell::get), CLONE_FROMS.with(Cell::get));
+
+        let source: LinkedList<Counted> = (0..5).map(Counted).collect();
+        let mut list: LinkedList<Counted> = (10..15).map(Counted).collect();
+        let capacity = list.capacity();
+        list.clone_from(&source);
+        assert_eq!(list, source);
+        assert_eq!((0, 5), counts());
+        assert_eq!(capacity, list.capacity());
+
+        // longer source clones the extra elements
+        let source: LinkedList<Counted> = (0..8).map(Counted).collect();
+        list.clone_from(&source);
+        assert_eq!(list, source);
+        assert_eq!((3, 10), counts());
+
+        // shorter source drops the extra elements
+        let source: LinkedList<Counted> = (0..2).map(Counted).collect();
+        list.clone_from(&source);
+        assert_eq!(list, source);
+        assert_eq!((3, 12), counts());
+        assert_eq!(Some(&Counted(1)), list.back());
+    }
+    #[test]
+    fn iter_nth() {
+        let mut list: LinkedList<u32> = (0..10).collect();
+
+        let mut iter = list.iter();
+        assert_eq!(Some(&2), iter.nth(2));
+        assert_eq!(Some(&7), iter.nth_back(2));
+        assert_eq!(4, iter.len());
+        assert_eq!(Some(&6), iter.nth(3));
+        assert_eq!(None, iter.next());
+        let mut iter = list.iter();
+        assert_eq!(None, iter.nth(10));
+        assert_eq!(0, iter.len());
+        assert_eq!(None, list.iter().nth_back(10));
+        assert_eq!(Some(&0), list.iter().nth_back(9));
+        assert_eq!(Some(&8), list.iter_rev().nth(1));
+        assert_eq!(Some(&1), list.iter_rev().nth_back(1));
+
+        let mut iter = list.iter_mut();
+        assert_eq!(Some(&mut 2), iter.nth(2));
+        assert_eq!(Some(&mut 7), iter.nth_back(2));
+        assert_eq!(4, iter.len());
+        assert_eq!(Some(&mut 6), iter.nth(3));
+        assert_eq!(None, iter.next());
+        assert_eq!(None, list.iter_mut().nth(10));
+        assert_eq!(Some(&mut 9), list.iter_mut().nth(9));
+        assert_eq!(Some(&mut 8), list.iter_mut_rev().nth(1));
+
+        let mut iter = list.clone().into_iter();
+        assert_eq!(Some(2), iter.nth(2));
+        assert_eq!(Some(7), iter.nth_back(2));
+        assert_eq!(4, iter.len());
+        assert_eq!(Some(6), iter.nth(3));
+        assert_eq!(None, iter.next());
+        let mut iter = list.clone().into_iter();
+        assert_eq!(None, iter.nth(10));
+        assert_eq!(0, iter.len());
+        assert_eq!(Some(0), list.into_iter().nth_back(9));
+    }
+    #[test]
+    fn into_iter_nth_drops() {
+        use std::rc::Rc;
+        let counter = Rc::new(());
+        let list: LinkedList<Rc<()>> = (0..10).map(|_| counter.clone()).collect();
+        let mut iter = list.into_iter();
+        drop(iter.nth(3));
+        assert_eq!(7, Rc::strong_count(&counter));
+        drop(iter.nth_back(1));
+        assert_eq!(5, Rc::strong_count(&counter));
+        assert_eq!(None, iter.nth(4));
+        assert_eq!(1, Rc::strong_count(&counter));
+        assert_eq!(10, iter.into_list().capacity());
+    }
+    #[test]
+    fn iter_fold_rfold() {
+        let mut list: LinkedList<u32> = (1..=10).collect();
+
+        let mut forward = Vec::new();
+        for &i in list.iter() {
+            forward.push(i);
+        }
+        let folded = list.iter().fold(Vec::new(), |mut acc, &i| {
+            acc.push(i);
+            acc
+        });
+        assert_eq!(forward, folded);
+        let rfolded = list.iter().rfold(Vec::new(), |mut acc, &i| {
+            acc.push(i);
+            acc
+        });
+        forward.reverse();
+        assert_eq!(forward, rfolded);
+
+        // partially consumed iterators only fold the remaining elements
+        let mut iter = list.iter();
+        iter.next();
+        iter.next_back();
+        assert_eq!(
+            vec![2, 3, 4, 5, 6, 7, 8, 9],
+            iter.cloned().collect::<Vec<u32>>()
+        );
+        assert_eq!(
+            vec![9, 8, 7, 6, 5, 4, 3, 2],
+            iter.rev().cloned().collect::<Vec<u32>>()
+        );
+        let rev: Vec<u32> = list.iter_rev().fold(Vec::new(), |mut acc, &i| {
+            acc.push(i);
+            acc
+        });
+        assert_eq!(forward, rev);
+
+        list.iter_mut().fold(0, |acc, i| {
+            *i += acc;
+            *i
+        });
+        assert_eq!(list, vec![1, 3, 6, 10, 15, 21, 28, 36, 45, 55]);
+        let order = list.iter_mut().rfold(Vec::new(), |mut acc, i| {
+            acc.push(*i);
+            acc
+        });
+        assert_eq!(order, vec![55, 45, 36, 28, 21, 15, 10, 6, 3, 1]);
+        let mut sum = 0;
+        list.iter_mut_rev().for_each(|i| sum += *i);
+        assert_eq!(220, sum);
+    }
+    #[test]
+    fn swap_remove_like_vec() {
+        let indices = [3, 0, 5, 2, 2, 1, 0];
+        let mut list: LinkedList<u32> = (0..10).collect();
+        let mut vec: Vec<u32> = (0..10).collect();
+        for &index in &indices {
+            // Vec::swap_remove uses the last element, so swap with the front manually
+            vec.swap(0, index);
+            assert_eq!(vec.remove(0), list.swap_remove_front(index));
+            assert_eq!(list, vec);
+        }
+
+        let mut list: LinkedList<u32> = (0..10).collect();
+        let mut vec: Vec<u32> = (0..10).collect();
+        for &index in &indices {
+            assert_eq!(vec.swap_remove(index), list.swap_remove_back(index));
+            assert_eq!(list, vec);
+        }
+        let rev: Vec<u32> = list.iter_rev().cloned().collect();
+        assert_eq!(rev, vec.into_iter().rev().collect::<Vec<u32>>());
+    }
+    #[test]
+    #[should_panic]
+    fn swap_remove_out_of_bounds() {
+        let mut list: LinkedList<u32> = (0..3).collect();
+        list.swap_remove_front(3);
+    }
+    #[test]
+    fn cursor_rotate_to_front() {
+        let mut vec: Vec<u32> = (0..7).collect();
+        let mut list: LinkedList<u32> = vec.iter().cloned().collect();
+        for &index in &[3, 6, 0, 1, 5] {
+            list.cursor_mut_at(index).unwrap().rotate_to_front();
+            vec.rotate_left(index);
+            assert_eq!(list, vec);
+            let rev: Vec<u32> = list.iter_rev().cloned().collect();
+            assert_eq!(rev, vec.iter().rev().cloned().collect::<Vec<u32>>());
+            assert_eq!(Some(&vec[0]), list.front());
+            assert_eq!(Some(&vec[6]), list.back());
+        }
+
+        let mut single: LinkedList<u32> = Some(1).into_iter().collect();
+        single.cursor_mut_front().unwrap().rotate_to_front();
+        assert_eq!(single, vec![1]);
+    }
+    #[test]
+    fn cursor_where() {
+        let mut list: LinkedList<u32> = vec![1, 4, 2, 8, 5, 7].into_iter().collect();
+
+        list.first_cursor_where(|&i| i % 2 == 0)
+            .unwrap()
+            .insert_prev(3);
+        assert_eq!(list, vec![1, 3, 4, 2, 8, 5, 7]);
+
+        assert_eq!(8, list.last_cursor_where(|&i| i % 2 == 0).unwrap().remove());
+        assert_eq!(list, vec![1, 3, 4, 2, 5, 7]);
+
+        assert_eq!(3, list.last_cursor_where(|&i| i % 2 == 0).unwrap().index());
+        assert_eq!(0, list.first_cursor_where(|&i| i == 1).unwrap().index());
+        assert_eq!(5, list.last_cursor_where(|&i| i == 7).unwrap().index());
+        assert_eq!(3, list.first_cursor_where(|&i| i == 3).unwrap().remove());
+        assert_eq!(list, vec![1, 4, 2, 5, 7]);
+
+        assert!(list.first_cursor_where(|&i| i > 10).is_none());
+        assert!(list.last_cursor_where(|&i| i > 10).is_none());
+        let mut empty: LinkedList<u32> = LinkedList::new();
+        assert!(empty.first_cursor_where(|_| true).is_none());
+        assert!(empty.last_cursor_where(|_| true).is_none());
+    }
+    #[test]
+    fn take_leaves_empty() {
+        let mut list: LinkedList<u32> = LinkedList::with_capacity_and_chunk_size(5, 7);
+        list.extend(0..20);
+        let capacity = list.capacity();
+        let allocations = list.number_of_allocations();
+
+        let mut taken = list.take();
+        assert_eq!(taken, (0..20).collect::<Vec<u32>>());
+        assert_eq!(capacity, taken.capacity());
+        assert_eq!(allocations, taken.number_of_allocations());
+        assert_eq!(7, taken.chunk_size());
+
+        assert!(list.is_empty());
+        assert_eq!(0, list.capacity());
+        assert_eq!(0, list.number_of_allocations());
+        assert_eq!(7, list.chunk_size());
+
+        // both lists are still usable
+        list.push_back(1);
+        taken.push_back(20);
+        assert_eq!(list, vec![1]);
+        assert_eq!(Some(&20), taken.back());
+    }
+    #[test]
+    #[should_panic(expected = "capacity overflow")]
+    fn reserve_overflow_len() {
+        let mut list: LinkedList<u32> = LinkedList::new();
+        list.push_back(1);
+        list.reserve(usize::MAX);
+    }
+    #[test]
+    #[should_panic(expected = "capacity overflow")]
+    fn reserve_overflow_empty() {
+        let mut list: LinkedList<u32> = LinkedList::new();
+        list.reserve(usize::MAX);
+    }
+    #[test]
+    #[cfg(target_pointer_width = "64")]
+    #[should_panic(expected = "capacity overflow")]
+    fn reserve_exact_overflow_bytes() {
+        // the count fits in a usize, but the size in bytes doesn't fit in an isize
+        let mut list: LinkedList<u64> = LinkedList::new();
+        list.reserve_exact(1 << 60);
+    }
+    #[test]
+    fn shrink_to_floor() {
+        let mut list: LinkedList<u32> = LinkedList::with_chunk_size(10);
+        for i in 0..50 {
+            list.push_back(i);
+        }
+        assert_eq!(5, list.number_of_allocations());
+        list.truncate(15);
+
+        list.shrink_to(25);
+        assert!(list.capacity() >= 25);
+        assert!(list.capacity() >= list.len());
+        assert_eq!(30, list.capacity());
+        assert_eq!(3, list.number_of_allocations());
+        assert_eq!(list, (0..15).collect::<Vec<u32>>());
+
+        // the remaining unused nodes can be used without allocating
+        for i in 15..30 {
+            list.push_back(i);
+        }
+        assert_eq!(30, list.capacity());
+        assert_eq!(list, (0..30).collect::<Vec<u32>>());
+
+        // nothing is unused, so there is nothing to free
+        list.shrink_to(0);
+        assert_eq!(30, list.capacity());
+
+        // a floor above the capacity does nothing
+        list.truncate(5);
+        list.shrink_to(100);
+        assert_eq!(30, list.capacity());
+        list.shrink_to(0);
+        assert_eq!(10, list.capacity());
+        assert_eq!(list, vec![0, 1, 2, 3, 4]);
+        list.extend(5..40);
+        assert_eq!(list, (0..40).collect::<Vec<u32>>());
+    }
+    #[test]
+    fn shrink_to_drops() {
+        use std::rc::Rc;
+        let counter = Rc::new(());
+        let mut list: LinkedList<Rc<()>> = LinkedList::with_chunk_size(3);
+        for _ in 0..9 {
+            list.push_front(counter.clone());
+        }
+        for _ in 0..5 {
+            list.pop_back();
+        }
+        list.shrink_to_fit();
+        assert_eq!(5, Rc::strong_count(&counter));
+        assert!(list.capacity() < 9);
+        drop(list);
+        assert_eq!(1, Rc::strong_count(&counter));
+    }
+    #[test]
+    fn retain_indexed_original_positions() {
+        let mut list: LinkedList<u32> = (100..110).collect();
+        let mut seen = Vec::new();
+        list.retain_indexed(|i, &val| {
+            seen.push((i, val));
+            i % 2 == 1
+        });
+        assert_eq!(list, vec![101, 103, 105, 107, 109]);
+        assert_eq!(
+            seen,
+            (0..10).map(|i| (i, 100 + i as u32)).collect::<Vec<_>>()
+        );
+
+        // indexes are the positions before this call, not counting removed elements
+        list.retain_indexed(|i, &val| {
+            assert_eq!(101 + 2 * i as u32, val);
+            val != 105
+        });
+        assert_eq!(list, vec![101, 103, 107, 109]);
+    }
+    #[test]
+    fn debug_print_cursors() {
+        let mut list: LinkedList<u32> = vec![10, 20, 42, 30].into_iter().collect();
+
+        let cursor = list.cursor_ref_at(2).unwrap();
+        assert_eq!("CursorRef { index: 2, value: 42 }", format!("{:?}", cursor));
+        assert_eq!(
+            "CursorRef { index: 0, value: 10 }",
+            format!("{:?}", list.cursor_ref_front().unwrap())
+        );
+
+        let mut cursor = list.cursor_mut_at(2).unwrap();
+        assert_eq!("CursorMut { index: 2, value: 42 }", format!("{:?}", cursor));
+        cursor.go_next();
+        assert_eq!("CursorMut { index: 3, value: 30 }", format!("{:?}", cursor));
+    }
+    #[test]
+    fn insert_sorted_like_vec() {
+        let mut rng = thread_rng();
+        let mut input: Vec<u32> = (0..100).map(|i| i % 37).collect();
+        rng.shuffle(&mut input);
+
+        let mut list: LinkedList<u32> = LinkedList::new();
+        let mut vec: Vec<u32> = Vec::new();
+        for &value in &input {
+            let index = list.insert_sorted(value);
+            // equal elements go after the existing ones
+            let expected = vec.iter().take_while(|&&v| v <= value).count();
+            assert_eq!(expected, index);
+            vec.insert(index, value);
+        }
+        input.sort();
+        assert_eq!(list, input);
+        let rev: Vec<u32> = list.iter_rev().cloned().collect();
+        assert_eq!(rev, input.into_iter().rev().collect::<Vec<u32>>());
+
+        // stability: the second element of the tuples are in insertion order
+        let mut list: LinkedList<(u32, usize)> = LinkedList::new();
+        for (i, &key) in [3, 1, 3, 2, 1, 3].iter().enumerate() {
+            list.insert_sorted_by((key, i), |a, b| a.0.cmp(&b.0));
+        }
+        assert_eq!(list, vec![(1, 1), (1, 4), (2, 3), (3, 0), (3, 2), (3, 5)]);
+    }
+    #[test]
+    fn extend_front_order() {
+        let mut list: LinkedList<u32> = (100..103).collect();
+        list.extend_front((0..20).filter(|i| i % 3 != 0).map(|i| i * 2));
+        let mut expected: Vec<u32> = (0..20).filter(|i| i % 3 != 0).map(|i| i * 2).collect();
+        expected.extend(100..103);
+        assert_eq!(list, expected);
+        let rev: Vec<u32> = list.iter_rev().cloned().collect();
+        assert_eq!(rev, expected.iter().rev().cloned().collect::<Vec<u32>>());
+
+        list.extend_front(None);
+        assert_eq!(list, expected);
+
+        let mut empty: LinkedList<u32> = LinkedList::new();
+        empty.extend_front(vec![1, 2]);
+        assert_eq!(empty, vec![1, 2]);
+        assert_eq!(Some(&2), empty.back());
+    }
+    #[test]
+    fn pop_n() {
+        let mut list: LinkedList<u32> = (0..10).collect();
+        let capacity = list.capacity();
+
+        let front = list.pop_front_n(3);
+        assert_eq!(front, vec![0, 1, 2]);
+        assert_eq!(3, front.capacity());
+        let back = list.pop_back_n(3);
+        assert_eq!(back, vec![7, 8, 9]);
+        assert_eq!(list, vec![3, 4, 5, 6]);
+        assert_eq!(capacity, list.capacity());
+
+        // exactly len
+        let all = list.pop_back_n(4);
+        assert_eq!(all, vec![3, 4, 5, 6]);
+        assert!(list.is_empty());
+
+        // more than len
+        list.extend(0..3);
+        assert_eq!(list.pop_front_n(5), vec![0, 1, 2]);
+        assert!(list.is_empty());
+        assert_eq!(capacity, list.capacity());
+
+        let none = list.pop_front_n(2);
+        assert!(none.is_empty());
+        assert_eq!(0, none.capacity());
+        assert!(list.pop_back_n(1).is_empty());
+    }
+    #[test]
+    fn cursor_remove_range() {
+        let mut list: LinkedList<u32> = (0..10).collect();
+        let capacity = list.capacity();
+        {
+            let (removed, cursor) = list.cursor_mut_at(3).unwrap().remove_range(4);
+            assert_eq!(removed, vec![3, 4, 5, 6]);
+            let cursor = cursor.unwrap();
+            assert_eq!(&7, cursor.get_ref());
+            assert_eq!(3, cursor.index());
+        }
+        assert_eq!(list, vec![0, 1, 2, 7, 8, 9]);
+        assert_eq!(capacity, list.capacity());
+
+        // reaching the back
+        {
+            let (removed, cursor) = list.cursor_mut_at(4).unwrap().remove_range(10);
+            assert_eq!(removed, vec![8, 9]);
+            assert!(cursor.is_none());
+        }
+        assert_eq!(list, vec![0, 1, 2, 7]);
+        assert_eq!(Some(&7), list.back());
+
+        // removing nothing
+        {
+            let (removed, cursor) = list.cursor_mut_at(1).unwrap().remove_range(0);
+            assert!(removed.is_empty());
+            let cursor = cursor.unwrap();
+            assert_eq!(&1, cursor.get_ref());
+            assert_eq!(1, cursor.index());
+        }
+        assert_eq!(list, vec![0, 1, 2, 7]);
+
+        // removing everything
+        let (removed, cursor) = list.cursor_mut_front().unwrap().remove_range(4);
+        assert_eq!(removed, vec![0, 1, 2, 7]);
+        assert!(cursor.is_none());
+        assert!(list.is_empty());
+        assert_eq!(None, list.front());
+    }
+    #[test]
+    fn chunks_sizes() {
+        let list: LinkedList<u32> = (0..10).collect();
+        let chunks = list.chunks(3);
+        assert_eq!(4, chunks.len());
+        let chunks: Vec<Vec<&u32>> = chunks.collect();
+        let sizes: Vec<usize> = chunks.iter().map(Vec::len).collect();
+        assert_eq!(sizes, vec![3, 3, 3, 1]);
+        let flat: Vec<u32> = chunks.into_iter().flatten().cloned().collect();
+        assert_eq!(list, flat);
+
+        let mut chunks = list.chunks(5);
+        assert_eq!(Some(vec![&0, &1, &2, &3, &4]), chunks.next());
+        assert_eq!(1, chunks.len());
+        assert_eq!(Some(vec![&5, &6, &7, &8, &9]), chunks.next());
+        assert_eq!(None, chunks.next());
+
+        assert_eq!(1, list.chunks(100).count());
+        let empty: LinkedList<u32> = LinkedList::new();
+        assert_eq!(0, empty.chunks(3).count());
+    }
+    #[test]
+    #[should_panic]
+    fn chunks_zero() {
+        let list: LinkedList<u32> = (0..10).collect();
+        list.chunks(0);
+    }
+    #[test]
+    fn windows_overlap() {
+        let list: LinkedList<u32> = (1..=4).collect();
+        let windows: Vec<Vec<&u32>> = list.windows(2).collect();
+        assert_eq!(windows, vec![vec![&1, &2], vec![&2, &3], vec![&3, &4]]);
+
+        let sums: Vec<u32> = list.windows(3).map(|w| w.into_iter().sum()).collect();
+        assert_eq!(sums, vec![6, 9]);
+
+        let mut windows = list.windows(4);
+        assert_eq!(1, windows.len());
+        assert_eq!(Some(vec![&1, &2, &3, &4]), windows.next());
+        assert_eq!(0, windows.len());
+        assert_eq!(None, windows.next());
+
+        assert_eq!(4, list.windows(1).count());
+        assert_eq!(0, list.windows(5).len());
+        let empty: LinkedList<u32> = LinkedList::new();
+        assert_eq!(None, empty.windows(1).next());
+    }
+    #[test]
+    #[should_panic]
+    fn windows_zero() {
+        let list: LinkedList<u32> = (0..10).collect();
+        list.windows(0);
+    }
+    #[test]
+    fn for_each_like_iter() {
+        let mut list: LinkedList<u32> = (0..100).map(|i| i * 7 % 13).collect();
+
+        let mut by_iter = Vec::new();
+        list.iter().for_each(|&i| by_iter.push(i));
+        let mut by_ref = Vec::new();
+        list.for_each_ref(|&i| by_ref.push(i));
+        assert_eq!(by_iter, by_ref);
+
+        let mut other = list.clone();
+        let mut counter = 0;
+        list.iter_mut().for_each(|i| {
+            counter += 1;
+            *i += counter;
+        });
+        let mut counter = 0;
+        other.for_each_mut(|i| {
+            counter += 1;
+            *i += counter;
+        });
+        assert_eq!(list, other);
+
+        let empty: LinkedList<u32> = LinkedList::new();
+        empty.for_each_ref(|_| unreachable!());
+    }
+    #[test]
+    fn iter_from_cursor() {
+        let mut list: LinkedList<u32> = LinkedList::with_chunk_size(2);
+        list.extend(0..10);
+
+        let mut cursor = list.cursor_ref_front().unwrap();
+        for i in 0..10 {
+            let expected: Vec<u32> = (i..10).collect();
+            let iter = list.iter_from_cursor(cursor);
+            assert_eq!(iter.len(), expected.len());
+            assert_eq!(iter.cloned().collect::<Vec<u32>>(), expected);
+            let rev: Vec<u32> = list.iter_from_cursor(cursor).rev().cloned().collect();
+            assert_eq!(rev, expected.into_iter().rev().collect::<Vec<u32>>());
+            if let Some(next) = cursor.next() {
+                cursor = next;
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "cursor does not point into this list")]
+    fn iter_from_cursor_other_list() {
+        let mut a: LinkedList<u32> = LinkedList::new();
+        a.extend(0..3);
+        let b = a.clone();
+        let cursor = b.cursor_ref_front().unwrap();
+        let _ = a.iter_from_cursor(cursor);
+    }
+    #[test]
+    fn hash_includes_len() {
+        use std::collections::hash_map::DefaultHasher;
+        fn hash<H: Hash>(value: &H) -> u64 {
+            let mut hasher = DefaultHasher::new();
+            value.hash(&mut hasher);
+            hasher.finish()
+        }
+
+        let a: LinkedList<u32> = vec![1, 2].into_iter().collect();
+        let b: LinkedList<u32> = vec![1, 2, 0].into_iter().collect();
+        assert_ne!(hash(&a), hash(&b));
+        assert_eq!(hash(&a), hash(&a.clone()));
+
+        // the elements of a unit list hash to nothing at all
+        let units1: LinkedList<()> = vec![()].into_iter().collect();
+        let units2: LinkedList<()> = vec![(), ()].into_iter().collect();
+        assert_ne!(hash(&units1), hash(&units2));
+
+        let empty: LinkedList<u32> = LinkedList::new();
+        let one: LinkedList<u32> = vec![1].into_iter().collect();
+        let two: LinkedList<u32> = vec![2].into_iter().collect();
+        let both: LinkedList<u32> = vec![1, 2].into_iter().collect();
+        assert_ne!(hash(&(one, two)), hash(&(both, empty)));
+
+        // equal lists with different layouts hash equally
+        let mut c: LinkedList<u32> = LinkedList::with_chunk_size(1);
+        c.push_back(2);
+        c.push_front(1);
+        assert_eq!(hash(&a), hash(&c));
+    }
+    #[test]
+    fn get_pair_mut() {
+        let mut list: LinkedList<u32> = LinkedList::with_chunk_size(3);
+        list.extend(0..10);
+
+        for i in 0..10 {
+            for j in 0..10 {
+                match list.get_pair_mut(i, j) {
+                    Some((a, b)) => {
+                        assert_ne!(i, j);
+                        assert_eq!((*a, *b), (i as u32, j as u32));
+                        mem::swap(a, b);
+                    }
+                    None => assert_eq!(i, j),
+                }
+                if i != j {
+                    list.swap(i, j);
+                }
+                assert_eq!(list, (0..10).collect::<Vec<u32>>());
+            }
+        }
+
+        {
+            let (a, b) = list.get_pair_mut(0, 9).unwrap();
+            *a = 100;
+            *b = 200;
+        }
+        assert_eq!(list.front(), Some(&100));
+        assert_eq!(list.back(), Some(&200));
+
+        assert!(list.get_pair_mut(4, 4).is_none());
+        assert!(list.get_pair_mut(10, 0).is_none());
+        assert!(list.get_pair_mut(0, 10).is_none());
+    }
+    #[test]
+    fn append_n() {
+        let mut a: LinkedList<u32> = LinkedList::with_chunk_size(3);
+        let mut b: LinkedList<u32> = LinkedList::with_chunk_size(2);
+        a.extend(0..4);
+        b.extend(4..10);
+        let cap_b = b.capacity();
+
+        a.append_n(&mut b, 0);
+        assert_eq!(a, (0..4).collect::<Vec<u32>>());
+        assert_eq!(b, (4..10).collect::<Vec<u32>>());
+
+        a.append_n(&mut b, 3);
+        assert_eq!(a, (0..7).collect::<Vec<u32>>());
+        assert_eq!(b, (7..10).collect::<Vec<u32>>());
+        assert_eq!(cap_b, b.capacity());
+        assert_eq!(
+            a.iter().rev().cloned().collect::<Vec<u32>>(),
+            (0..7).rev().collect::<Vec<u32>>()
+        );
+
+        let cap_a = a.capacity();
+        a.append_n(&mut b, 3);
+        assert_eq!(a, (0..10).collect::<Vec<u32>>());
+        assert!(b.is_empty());
+        assert_eq!(cap_a + cap_b, a.capacity());
+        assert_eq!(0, b.capacity());
 
-            ptr::write(node, LinkedNode { next, prev, value });
-            node
-        }
+        b.extend(10..12);
+        a.append_n(&mut b, 1);
+        assert_eq!(a, (0..11).collect::<Vec<u32>>());
+        assert_eq!(b, vec![11]);
     }
+    #[test]
+    fn cursor_distance_to() {
+        let mut list: LinkedList<u32> = LinkedList::with_chunk_size(2);
+        list.extend(0..6);
 
-    fn allocate(&mut self, amount: usize) {
-        if amount == 0 {
-            return;
+        let front = list.cursor_ref_front().unwrap();
+        let mut cursor = front;
+        for i in 0..6 {
+            assert_eq!(Some(i), front.distance_to(cursor));
+            assert_eq!(Some(-i), cursor.distance_to(front));
+            assert_eq!(Some(0), cursor.distance_to(cursor));
+            if let Some(next) = cursor.next() {
+                cursor = next;
+            }
         }
-        let mut vec = Vec::with_capacity(amount);
-        let base = vec.as_mut_ptr();
-        let capacity = vec.capacity();
-        self.capacity += capacity;
+    }
+    #[test]
+    fn cursor_same_list() {
+        let mut a: LinkedList<u32> = LinkedList::new();
+        a.extend(0..4);
+        let mut b = a.clone();
 
-        mem::forget(vec);
+        let a_front = a.cursor_ref_front().unwrap();
+        let a_back = a.cursor_ref_back().unwrap();
+        let b_front = b.cursor_ref_front().unwrap();
+        assert!(a_front.same_list(a_back));
+        assert!(a_back.same_list(a_front.next().unwrap()));
+        assert!(!a_front.same_list(b_front));
+        assert_eq!(None, a_front.distance_to(b_front));
 
-        self.allocations.push((base, capacity));
+        // the identity is still unique after reallocating the nodes
+        b.compact();
+        let b_front = b.cursor_ref_front().unwrap();
+        assert!(b_front.same_list(b.cursor_ref_back().unwrap()));
+        assert!(!b_front.same_list(a.cursor_ref_front().unwrap()));
 
-        // add them to the unused_nodes list in reverse order, so they end up in the
-        // correct order if lots of elements are added with push_back
-        for i in (0..capacity).rev() {
-            let ptr = unsafe { base.add(i) };
+        let a_front = a.cursor_ref_front().unwrap();
+        let cursor = b.cursor_mut_back().unwrap();
+        assert!(cursor.same_list(cursor.as_cursor_ref()));
+        assert!(!cursor.same_list(a_front));
+    }
+    #[test]
+    fn into_iter_as_iter_and_clone() {
+        let mut list: LinkedList<String> = LinkedList::with_chunk_size(2);
+        list.extend((0..6).map(|i| i.to_string()));
 
-            unsafe {
-                (*ptr).next = self.unused_nodes;
-            }
-            self.unused_nodes = ptr;
-        }
+        let mut iter = list.into_iter();
+        assert_eq!(Some("0".to_string()), iter.next());
+        assert_eq!(Some("5".to_string()), iter.next_back());
+        assert_eq!(
+            format!("{:?}", iter.as_iter().collect::<Vec<_>>()),
+            r#"["1", "2", "3", "4"]"#
+        );
+        assert_eq!(4, iter.as_iter().len());
+
+        let mut clone = iter.clone();
+        assert_eq!(Some("1".to_string()), clone.next());
+        assert_eq!(Some("4".to_string()), clone.next_back());
+        assert_eq!(clone.collect::<Vec<String>>(), ["2", "3"]);
+
+        // the original is unaffected by the clone
+        assert_eq!(iter.collect::<Vec<String>>(), ["1", "2", "3", "4"]);
     }
-}
+    #[test]
+    fn clear_and_set_chunk_size() {
+        let mut list: LinkedList<u32> = LinkedList::with_chunk_size(2);
+        list.extend(0..4);
+        assert_eq!(4, list.capacity());
 
-impl<T> Drop for LinkedList<T> {
-    fn drop(&mut self) {
-        unsafe {
-            let mut ptr = self.head;
-            while !ptr.is_null() {
-                ptr::drop_in_place(&mut (*ptr).value);
-                ptr = (*ptr).next;
-            }
+        list.clear_and_set_chunk_size(5);
+        assert!(list.is_empty());
+        assert_eq!(5, list.chunk_size());
+        assert_eq!(4, list.capacity());
 
-            for &(vecptr, capacity) in &self.allocations {
-                let vec = Vec::from_raw_parts(vecptr, 0, capacity);
-                drop(vec);
-            }
-        }
+        // the recycled nodes are used first
+        list.extend(0..4);
+        assert_eq!(4, list.capacity());
+        assert_eq!(1, list.number_of_allocations());
+
+        // then a new allocation of the new chunk size is made
+        list.push_back(4);
+        assert_eq!(9, list.capacity());
+        assert_eq!(2, list.number_of_allocations());
+        assert_eq!(list, (0..5).collect::<Vec<u32>>());
     }
-}
-impl<T> Default for LinkedList<T> {
-    fn default() -> LinkedList<T> {
-        LinkedList::new()
+
+    #[test]
+    #[should_panic]
+    fn clear_and_set_chunk_size_zero() {
+        let mut list: LinkedList<u32> = LinkedList::new();
+        list.extend(0..4);
+        list.clear_and_set_chunk_size(0);
     }
-}
-impl<T: Clone> Clone for LinkedList<T> {
-    fn clone(&self) -> LinkedList<T> {
-        let mut list = LinkedList::with_capacity(self.len());
-        for item in self.iter() {
-            list.push_back(item.clone());
+    #[test]
+    fn cursor_move_node_after() {
+        // try every combination of source and target
+        for len in 1..6u32 {
+            for from in 0..len {
+                for target in 0..len {
+                    let mut list: LinkedList<u32> = (0..len).collect();
+                    let mut expected: Vec<u32> = (0..len).collect();
+                    let value = expected.remove(from as usize);
+                    let pos = if target < from {
+                        target + 1
+                    } else if target == from {
+                        from
+                    } else {
+                        target
+                    };
+                    expected.insert(pos as usize, value);
+
+                    {
+                        let mut cursor = list.cursor_mut_at(from as usize).unwrap();
+                        cursor.move_node_after(target as usize);
+                        assert_eq!(pos as usize, cursor.index());
+                        assert_eq!(&value, cursor.get_ref());
+                    }
+                    assert_eq!(list, expected);
+                    let rev: Vec<u32> = list.iter().rev().cloned().collect();
+                    assert_eq!(rev, expected.iter().rev().cloned().collect::<Vec<u32>>());
+                }
+            }
         }
-        list
-    }
-    fn clone_from(&mut self, source: &Self) {
-        self.clear();
-        self.reserve_exact(source.len());
-        for item in source.iter() {
-            self.push_back(item.clone());
+
+        // a middle node to the back, then to just after the head
+        let mut list: LinkedList<u32> = (0..5).collect();
+        let ptr = {
+            let mut cursor = list.cursor_mut_at(2).unwrap();
+            let ptr = cursor.get_ref() as *const u32;
+            cursor.move_node_after(4);
+            assert!(cursor.is_back());
+            ptr
+        };
+        assert_eq!(list, vec![0, 1, 3, 4, 2]);
+        {
+            let mut cursor = list.cursor_mut_back().unwrap();
+            cursor.move_node_after(0);
+            assert_eq!(ptr, cursor.get_ref() as *const u32);
         }
+        assert_eq!(list, vec![0, 2, 1, 3, 4]);
     }
-}
-impl<T> FromIterator<T> for LinkedList<T> {
-    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
-        let iter = iter.into_iter();
-        let mut list = LinkedList::with_capacity(iter.size_hint().0);
-        for item in iter {
-            list.push_back(item);
-        }
-        list
+
+    #[test]
+    #[should_panic]
+    fn cursor_move_node_after_out_of_bounds() {
+        let mut list: LinkedList<u32> = (0..5).collect();
+        list.cursor_mut_front().unwrap().move_node_after(5);
     }
-}
-impl<T: Eq> Eq for LinkedList<T> {}
-impl<T: PartialEq<U>, U> PartialEq<LinkedList<U>> for LinkedList<T> {
-    fn eq(&self, other: &LinkedList<U>) -> bool {
-        if self.len() != other.len() {
-            return false;
-        }
-        for (a, b) in self.iter().zip(other.iter()) {
-            if a != b {
-                return false;
+    #[test]
+    fn cursor_insert_and_move() {
+        let mut list: LinkedList<u32> = LinkedList::with_chunk_size(2);
+        list.push_back(10);
+        {
+            let mut cursor = list.cursor_mut_front().unwrap();
+            for i in 11..15 {
+                let index = cursor.insert_next_and_move(i);
+                assert_eq!(index, cursor.index());
+                assert_eq!((i - 10) as usize, index);
+                assert_eq!(&i, cursor.get_ref());
+                assert!(cursor.is_back());
+            }
+            for i in (5..10).rev() {
+                let index = cursor.insert_prev_and_move(i);
+                assert_eq!(4, index);
+                assert_eq!(index, cursor.index());
+                assert_eq!(&i, cursor.get_ref());
             }
         }
-        true
+        assert_eq!(list, vec![10, 11, 12, 13, 5, 6, 7, 8, 9, 14]);
+
+        {
+            let mut cursor = list.cursor_mut_front().unwrap();
+            assert_eq!(0, cursor.insert_prev_and_move(0));
+            assert!(cursor.is_front());
+            assert_eq!(&0, cursor.get_ref());
+        }
+        assert_eq!(list.front(), Some(&0));
+        assert_eq!(list.len(), 11);
     }
-}
-impl<T: PartialEq<U>, U> PartialEq<Vec<U>> for LinkedList<T> {
-    fn eq(&self, other: &Vec<U>) -> bool {
-        if self.len() != other.len() {
-            return false;
+    #[test]
+    fn try_retain_map_panic_at_ends() {
+        for panic_at in 0..5 {
+            let mut list: LinkedList<u32> = (0..5).collect();
+            let capacity = list.capacity();
+            let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+                let _ = list.try_retain_map::<()>(|i| {
+                    if i == panic_at {
+                        panic!("try_retain_map");
+                    }
+                    Ok(Some(i))
+                });
+            }));
+            assert!(result.is_err());
+            assert!(list.is_empty());
+            assert_eq!(capacity, list.capacity());
+            list.extend(0..5);
+            assert_eq!(capacity, list.capacity());
+            assert_eq!(list, vec![0, 1, 2, 3, 4]);
         }
-        for (a, b) in self.iter().zip(other.iter()) {
-            if a != b {
-                return false;
+    }
+    #[test]
+    fn find_stops_early() {
+        let mut list: LinkedList<u32> = (0..10).collect();
+        let mut calls = 0;
+        assert_eq!(
+            Some(&3),
+            list.iter().find(|&&v| {
+                calls += 1;
+                v == 3
+            })
+        );
+        assert_eq!(4, calls);
+
+        calls = 0;
+        assert_eq!(
+            Some(&7),
+            list.iter().rev().find(|&&v| {
+                calls += 1;
+                v == 7
+            })
+        );
+        assert_eq!(3, calls);
+
+        calls = 0;
+        assert_eq!(
+            Some(&mut 5),
+            list.iter_mut().find(|v| {
+                calls += 1;
+                **v == 5
+            })
+        );
+        assert_eq!(6, calls);
+
+        // the iterator continues after the element that was found
+        let mut iter = list.iter();
+        assert_eq!(Some(&2), iter.find(|&&v| v == 2));
+        assert_eq!(7, iter.len());
+        assert_eq!(Some(&3), iter.next());
+        let mut iter = list.iter_mut();
+        assert_eq!(Some(&mut 7), iter.rfind(|v| **v == 7));
+        assert_eq!(7, iter.len());
+        assert_eq!(Some(&mut 6), iter.next_back());
+    }
+    #[test]
+    fn try_fold_breaks_early() {
+        let list: LinkedList<u32> = (0..10).collect();
+        let mut iter = list.iter();
+        let sum = iter.try_fold(0u32, |acc, &v| if v < 4 { Some(acc + v) } else { None });
+        assert_eq!(None, sum);
+        assert_eq!(5, iter.len());
+        assert_eq!(Some(&5), iter.next());
+        assert_eq!(
+            Some(45),
+            list.iter().try_fold(0u32, |acc, &v| Some(acc + v))
+        );
+        assert_eq!(
+            Err(6),
+            list.iter()
+                .rev()
+                .try_fold(0u32, |acc, &v| if v > 6 { Ok(acc + v) } else { Err(v) })
+        );
+    }
+    #[test]
+    fn into_iter_try_fold_drops_rest() {
+        use std::rc::Rc;
+        let counter = Rc::new(());
+        let list: LinkedList<Rc<()>> = (0..10).map(|_| counter.clone()).collect();
+        let mut iter = list.into_iter();
+        let mut seen = 0;
+        let res: Result<(), ()> = iter.try_fold((), |(), rc| {
+            seen += 1;
+            drop(rc);
+            if seen == 3 {
+                Err(())
+            } else {
+                Ok(())
             }
-        }
-        true
+        });
+        assert_eq!(Err(()), res);
+        assert_eq!(7, iter.len());
+        assert_eq!(8, Rc::strong_count(&counter));
+
+        let res: Option<()> = iter.try_rfold((), |(), _| None);
+        assert_eq!(None, res);
+        assert_eq!(6, iter.len());
+        assert_eq!(7, Rc::strong_count(&counter));
+
+        drop(iter);
+        assert_eq!(1, Rc::strong_count(&counter));
     }
-}
-impl<T: PartialEq<U>, U> PartialEq<[U]> for LinkedList<T> {
-    fn eq(&self, other: &[U]) -> bool {
-        if self.len() != other.len() {
-            return false;
+    #[test]
+    fn retain_collect() {
+        let mut list: LinkedList<u32> = LinkedList::with_chunk_size(4);
+        list.extend(0..20);
+        let capacity = list.capacity();
+
+        let removed = list.retain_collect(|&v| v % 3 == 0);
+        assert_eq!(list, vec![0, 3, 6, 9, 12, 15, 18]);
+        assert_eq!(removed, vec![1, 2, 4, 5, 7, 8, 10, 11, 13, 14, 16, 17, 19]);
+        assert_eq!(capacity, list.capacity());
+        assert_eq!(4, removed.chunk_size());
+        assert_eq!(Some(&19), removed.back());
+
+        // the nodes of the removed elements are reused
+        list.extend(removed);
+        assert_eq!(capacity, list.capacity());
+        assert_eq!(20, list.len());
+
+        let removed = list.retain_collect(|_| true);
+        assert!(removed.is_empty());
+        assert_eq!(20, list.len());
+        let removed = list.retain_collect(|_| false);
+        assert!(list.is_empty());
+        assert_eq!(20, removed.len());
+        assert!(list.retain_collect(|_| false).is_empty());
+    }
+    #[test]
+    fn cursor_index_consistent() {
+        let mut list: LinkedList<u32> = (0..10).collect();
+        {
+            let mut cursor = list.cursor_mut_at(4).unwrap();
+            cursor.assert_index_consistent();
+            cursor.insert_prev(20);
+            cursor.assert_index_consistent();
+            cursor.insert_next(21);
+            cursor.assert_index_consistent();
+            cursor.insert_prev_and_move(22);
+            cursor.assert_index_consistent();
+            cursor.insert_many(vec![23, 24]);
+            cursor.assert_index_consistent();
+            cursor.swap_next();
+            cursor.assert_index_consistent();
+            cursor.move_node_after(0);
+            cursor.assert_index_consistent();
+            cursor.move_node_after(12);
+            cursor.assert_index_consistent();
+            assert!(cursor.move_to(3));
+            cursor.assert_index_consistent();
+            assert!(cursor.go_prev());
+            cursor.assert_index_consistent();
+            let (_, cursor) = cursor.remove_go_next();
+            let cursor = cursor.unwrap();
+            cursor.assert_index_consistent();
+            let (_, cursor) = cursor.remove_go_prev();
+            let cursor = cursor.unwrap();
+            cursor.assert_index_consistent();
+            let (_, cursor) = cursor.remove_range(3);
+            cursor.unwrap().assert_index_consistent();
         }
-        for (a, b) in self.iter().zip(other.iter()) {
-            if a != b {
-                return false;
+        assert_eq!(10, list.len());
+    }
+    #[test]
+    fn cursor_remove_go_prev_at_ends() {
+        let mut list: LinkedList<u32> = (0..3).collect();
+
+        // removing the back returns the new back
+        let (value, cursor) = list.cursor_mut_back().unwrap().remove_go_prev();
+        assert_eq!(2, value);
+        let cursor = cursor.unwrap();
+        cursor.assert_index_consistent();
+        assert_eq!(1, cursor.index());
+        assert!(cursor.is_back());
+
+        // removing the front returns no cursor
+        let (value, cursor) = list.cursor_mut_front().unwrap().remove_go_prev();
+        assert_eq!(0, value);
+        assert!(cursor.is_none());
+        assert_eq!(list, vec![1]);
+    }
+    #[test]
+    fn append_unused_nodes_stress() {
+        let mut list: LinkedList<u32> = LinkedList::with_chunk_size(3);
+        for i in 0..40u32 {
+            let mut other = LinkedList::with_chunk_size(1 + (i as usize % 5));
+            other.reserve((i as usize * 7) % 11);
+            for j in 0..(i * 3) % 8 {
+                other.push_back(j);
+            }
+            // pop some values so the unused nodes are spread over the chunks
+            for _ in 0..i % 3 {
+                other.pop_front();
             }
+            if i % 4 == 0 {
+                list.clear();
+            }
+            list.append(&mut other);
+            assert_eq!(0, other.capacity());
+            assert_eq!(0, other.number_of_allocations());
         }
-        true
-    }
-}
-impl<'a, T: PartialEq<U>, U> PartialEq<&'a [U]> for LinkedList<T> {
-    fn eq(&self, other: &&'a [U]) -> bool {
-        if self.len() != other.len() {
-            return false;
+
+        // every unused node is still reachable, so filling the list doesn't allocate
+        let allocations = list.number_of_allocations();
+        let capacity = list.capacity();
+        while list.len() < capacity {
+            list.push_back(0);
         }
-        for (a, b) in self.iter().zip(other.iter()) {
-            if a != b {
-                return false;
-            }
+        assert_eq!(allocations, list.number_of_allocations());
+        assert_eq!(capacity, list.capacity());
+        list.push_back(0);
+        assert_eq!(allocations + 1, list.number_of_allocations());
+    }
+    #[test]
+    fn iter_mut_as_ref_iter() {
+        let mut list: LinkedList<u32> = (0..6).collect();
+        let mut iter = list.iter_mut();
+        *iter.next().unwrap() += 10;
+        *iter.next_back().unwrap() += 10;
+
+        let peek = iter.as_ref_iter();
+        assert_eq!(4, peek.len());
+        assert!(peek.eq([1, 2, 3, 4].iter()));
+        assert!(iter.as_ref_iter().rev().eq([4, 3, 2, 1].iter()));
+
+        for value in iter.by_ref().take(2) {
+            *value *= 2;
         }
-        true
+        assert!(iter.as_ref_iter().eq([3, 4].iter()));
+        iter.by_ref().count();
+        assert_eq!(0, iter.as_ref_iter().len());
+        assert_eq!(list, vec![10, 2, 4, 3, 4, 15]);
     }
-}
-impl<'a, T: PartialEq<U>, U> PartialEq<&'a mut [U]> for LinkedList<T> {
-    fn eq(&self, other: &&'a mut [U]) -> bool {
-        if self.len() != other.len() {
-            return false;
+    #[test]
+    fn split_into() {
+        let list: LinkedList<u32> = (0..10).collect();
+        let capacity = list.capacity();
+        let parts = list.split_into(3);
+        assert_eq!(3, parts.len());
+        assert_eq!(parts[0], vec![0, 1, 2, 3]);
+        assert_eq!(parts[1], vec![4, 5, 6]);
+        assert_eq!(parts[2], vec![7, 8, 9]);
+        assert_eq!(capacity, parts[0].capacity());
+        let joined: Vec<u32> = parts.iter().flat_map(|part| part.iter().cloned()).collect();
+        assert_eq!(joined, (0..10).collect::<Vec<u32>>());
+
+        let list: LinkedList<u32> = (0..10).collect();
+        let parts = list.split_into(11);
+        assert_eq!(11, parts.len());
+        for (i, part) in parts.iter().enumerate().take(10) {
+            assert_eq!(*part, vec![i as u32]);
         }
-        for (a, b) in self.iter().zip(other.iter()) {
-            if a != b {
-                return false;
+        assert!(parts[10].is_empty());
+        assert_eq!(0, parts[10].capacity());
+        let joined = LinkedList::concat(parts);
+        assert_eq!(joined, (0..10).collect::<Vec<u32>>());
+
+        let parts = LinkedList::<u32>::new().split_into(2);
+        assert!(parts.iter().all(|part| part.is_empty()));
+        assert_eq!(1, (0..5).collect::<LinkedList<u32>>().split_into(1).len());
+    }
+    #[test]
+    #[should_panic]
+    fn split_into_zero() {
+        let list: LinkedList<u32> = (0..10).collect();
+        list.split_into(0);
+    }
+    #[test]
+    fn cycle_iter() {
+        let list: LinkedList<u32> = (1..4).collect();
+        let items: Vec<u32> = list.cycle_iter().take(7).cloned().collect();
+        assert_eq!(items, [1, 2, 3, 1, 2, 3, 1]);
+
+        let mut iter = list.cycle_iter();
+        iter.nth(4);
+        let clone = iter.clone();
+        assert!(iter.take(4).eq(clone.take(4)));
+        assert_eq!((usize::MAX, None), list.cycle_iter().size_hint());
+
+        let single: LinkedList<u32> = Some(5).into_iter().collect();
+        assert!(single.cycle_iter().take(3).eq([5, 5, 5].iter()));
+
+        let empty: LinkedList<u32> = LinkedList::new();
+        let mut iter = empty.cycle_iter();
+        assert_eq!(None, iter.next());
+        assert_eq!(None, iter.next());
+        assert_eq!((0, Some(0)), iter.size_hint());
+    }
+    #[test]
+    fn count_runs() {
+        let empty: LinkedList<u32> = LinkedList::new();
+        assert_eq!(0, empty.count_runs());
+
+        let equal: LinkedList<u32> = vec![7; 5].into_iter().collect();
+        assert_eq!(1, equal.count_runs());
+
+        let distinct: LinkedList<u32> = (0..6).collect();
+        assert_eq!(6, distinct.count_runs());
+
+        let mut mixed: LinkedList<u32> = vec![1, 1, 2, 3, 3, 3, 1, 2, 2].into_iter().collect();
+        assert_eq!(5, mixed.count_runs());
+        mixed.dedup();
+        assert_eq!(mixed, vec![1, 2, 3, 1, 2]);
+        assert_eq!(5, mixed.count_runs());
+    }
+    #[test]
+    fn iterators_meet_in_the_middle() {
+        // alternates between the ends, starting at the back if `back_first` is set
+        fn alternate<I>(mut iter: I, back_first: bool) -> Vec<I::Item>
+        where
+            I: DoubleEndedIterator + ExactSizeIterator,
+        {
+            let len = iter.len();
+            let mut seen = Vec::new();
+            let mut back = back_first;
+            while let Some(v) = if back { iter.next_back() } else { iter.next() } {
+                seen.push(v);
+                back = !back;
+                assert_eq!(len - seen.len(), iter.len());
             }
+            assert!(iter.next().is_none());
+            assert!(iter.next_back().is_none());
+            seen
         }
-        true
-    }
-}
-impl<T: Ord> Ord for LinkedList<T> {
-    fn cmp(&self, other: &LinkedList<T>) -> Ordering {
-        for (a, b) in self.iter().zip(other.iter()) {
-            match a.cmp(b) {
-                Ordering::Equal => {}
-                ordering => {
-                    return ordering;
+
+        for &len in &[1u32, 2, 5] {
+            for &back_first in &[false, true] {
+                let mut list: LinkedList<u32> = (0..len).collect();
+
+                let mut seen: Vec<u32> = alternate(list.iter(), back_first)
+                    .into_iter()
+                    .cloned()
+                    .collect();
+                seen.sort();
+                assert_eq!(seen, (0..len).collect::<Vec<u32>>());
+
+                for v in alternate(list.iter_mut(), back_first) {
+                    *v += 100;
                 }
+                // every element was incremented exactly once
+                assert_eq!(list, (100..100 + len).collect::<Vec<u32>>());
+
+                let mut seen = alternate(list.into_iter(), back_first);
+                seen.sort();
+                assert_eq!(seen, (100..100 + len).collect::<Vec<u32>>());
             }
         }
-        Ordering::Equal
     }
-}
-impl<T: PartialOrd<U>, U> PartialOrd<LinkedList<U>> for LinkedList<T> {
-    fn partial_cmp(&self, other: &LinkedList<U>) -> Option<Ordering> {
-        for (a, b) in self.iter().zip(other.iter()) {
-            match a.partial_cmp(b) {
-                Some(Ordering::Equal) => {}
-                ordering => {
-                    return ordering;
+    #[test]
+    fn into_iter_meet_in_the_middle_drops_once() {
+        use std::rc::Rc;
+        for &(len, order) in &[(1, &[0][..]), (2, &[0, 1][..]), (5, &[0, 4, 1, 3, 2][..])] {
+            let counter = Rc::new(());
+            let list: LinkedList<(usize, Rc<()>)> =
+                (0..len).map(|i| (i, counter.clone())).collect();
+            let mut iter = list.into_iter();
+            let mut taken = Vec::new();
+            while let Some(item) = iter.next() {
+                taken.push(item);
+                match iter.next_back() {
+                    Some(item) => taken.push(item),
+                    None => break,
                 }
             }
+            // every value is read once, and the middle is read by next
+            assert!(taken.iter().map(|&(i, _)| i).eq(order.iter().cloned()));
+            assert_eq!(0, iter.len());
+            assert_eq!(len + 1, Rc::strong_count(&counter));
+            drop(iter);
+            assert_eq!(len + 1, Rc::strong_count(&counter));
+            drop(taken);
+            assert_eq!(1, Rc::strong_count(&counter));
         }
-        Some(Ordering::Equal)
     }
-}
-impl<T> Extend<T> for LinkedList<T> {
-    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
-        let iter = iter.into_iter();
-        self.reserve(iter.size_hint().0);
-        for item in iter {
-            self.push_back(item);
-        }
+    #[test]
+    fn retain_map_count() {
+        let mut list: LinkedList<u32> = (0..20).collect();
+        let capacity = list.capacity();
+        let len = list.len();
+        let removed = list.retain_map_count(|v| if v % 3 == 0 { Some(v / 3) } else { None });
+        assert_eq!(len - list.len(), removed);
+        assert_eq!(13, removed);
+        assert_eq!(list, vec![0, 1, 2, 3, 4, 5, 6]);
+        assert_eq!(capacity, list.capacity());
+
+        assert_eq!(0, list.retain_map_count(Some));
+        assert_eq!(7, list.retain_map_count(|_| None));
+        assert!(list.is_empty());
+        assert_eq!(0, list.retain_map_count(|_| None));
     }
-}
-impl<'a, T: 'a + Copy> Extend<&'a T> for LinkedList<T> {
-    fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
-        let iter = iter.into_iter();
-        self.reserve(iter.size_hint().0);
-        for item in iter {
-            self.push_back(*item);
+    #[test]
+    fn cursor_splice_list() {
+        let mut list: LinkedList<u32> = LinkedList::new();
+        list.extend(&[1, 5, 6]);
+        let mut other: LinkedList<u32> = LinkedList::with_capacity(5);
+        other.extend(&[2, 3, 4]);
+        let capacity = list.capacity() + other.capacity();
+        {
+            let mut cursor = list.cursor_mut_front().unwrap();
+            cursor.splice_after_list(&mut other);
+            assert_eq!(0, cursor.index());
+            assert_eq!(Some(&2), cursor.peek_next());
+            cursor.assert_index_consistent();
         }
+        assert_eq!(list, vec![1, 2, 3, 4, 5, 6]);
+        assert!(list.iter().rev().eq([6, 5, 4, 3, 2, 1].iter()));
+        assert!(other.is_empty());
+        assert_eq!(0, other.capacity());
+        assert_eq!(0, other.number_of_allocations());
+        assert_eq!(capacity, list.capacity());
+
+        // other can be reused
+        other.extend(&[7, 8, 9]);
+        {
+            let mut cursor = list.cursor_mut_at(3).unwrap();
+            cursor.splice_before_list(&mut other);
+            assert_eq!(6, cursor.index());
+            assert_eq!(&4, cursor.get_ref());
+            assert_eq!(Some(&9), cursor.peek_prev());
+            cursor.assert_index_consistent();
+        }
+        assert_eq!(list, vec![1, 2, 3, 7, 8, 9, 4, 5, 6]);
+        assert!(list.iter().rev().eq([6, 5, 4, 9, 8, 7, 3, 2, 1].iter()));
+        assert!(other.is_empty());
+        assert_eq!(0, other.capacity());
+
+        // splicing at the ends updates the head and tail
+        other.extend(&[0]);
+        list.cursor_mut_front()
+            .unwrap()
+            .splice_before_list(&mut other);
+        other.extend(&[10]);
+        list.cursor_mut_back()
+            .unwrap()
+            .splice_after_list(&mut other);
+        assert_eq!(Some(&0), list.front());
+        assert_eq!(Some(&10), list.back());
+        assert_eq!(11, list.len());
+
+        // splicing an empty list only moves its capacity
+        let mut empty: LinkedList<u32> = LinkedList::with_capacity(4);
+        let capacity = list.capacity();
+        list.cursor_mut_front()
+            .unwrap()
+            .splice_after_list(&mut empty);
+        assert_eq!(capacity + 4, list.capacity());
+        assert_eq!(11, list.len());
+        for i in 0..4 {
+            list.push_back(i);
+        }
+        assert_eq!(capacity + 4, list.capacity());
     }
-}
-impl<T> IntoIterator for LinkedList<T> {
-    type Item = T;
-    type IntoIter = IntoIter<T>;
-    fn into_iter(self) -> IntoIter<T> {
-        let iter = IntoIter {
-            head: self.head,
-            tail: self.tail,
-            len: self.len,
-            allocations: unsafe { ptr::read(&self.allocations) },
-        };
-        mem::forget(self);
-        iter
-    }
-}
-impl<'a, T> IntoIterator for &'a LinkedList<T> {
-    type Item = &'a T;
-    type IntoIter = Iter<'a, T>;
-    fn into_iter(self) -> Iter<'a, T> {
-        self.iter()
-    }
-}
-impl<'a, T> IntoIterator for &'a mut LinkedList<T> {
-    type Item = &'a mut T;
-    type IntoIter = IterMut<'a, T>;
-    fn into_iter(self) -> IterMut<'a, T> {
-        self.iter_mut()
+    #[test]
+    fn from_iter_with_chunk_size() {
+        let list = LinkedList::from_iter_with_chunk_size(0..1000, 500);
+        assert_eq!(list, (0..1000).collect::<Vec<u32>>());
+        assert_eq!(500, list.chunk_size());
+        assert_eq!(1, list.number_of_allocations());
+
+        // without a size hint, the list grows by whole chunks
+        let list = LinkedList::from_iter_with_chunk_size((0..1000).filter(|_| true), 500);
+        assert_eq!(1000, list.len());
+        assert_eq!(2, list.number_of_allocations());
+        assert_eq!(1000, list.capacity());
+        let list: LinkedList<u32> = (0..1000).filter(|_| true).collect();
+        assert_eq!(16, list.number_of_allocations());
+
+        let empty = LinkedList::<u32>::from_iter_with_chunk_size(None, 3);
+        assert!(empty.is_empty());
+        assert_eq!(3, empty.chunk_size());
+        assert_eq!(0, empty.number_of_allocations());
     }
-}
-impl<T: Hash> Hash for LinkedList<T> {
-    fn hash<H: Hasher>(&self, state: &mut H) {
-        for item in self.iter() {
-            item.hash(state);
-        }
+    #[test]
+    #[should_panic]
+    fn from_iter_with_zero_chunk_size() {
+        LinkedList::from_iter_with_chunk_size(0..10, 0);
     }
-}
-impl<T: fmt::Debug> fmt::Debug for LinkedList<T> {
-    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
-        let mut out = f.debug_list();
-        for item in self.iter() {
-            out.entry(item);
+    #[test]
+    fn capacity_is_exact() {
+        for &cap in &[1, 2, 3, 7, 63, 64, 65, 100, 293, 1000] {
+            let list: LinkedList<u32> = LinkedList::with_capacity(cap);
+            assert_eq!(cap, list.capacity());
+            let list: LinkedList<u8> = LinkedList::with_capacity_and_chunk_size(cap, 5);
+            assert_eq!(cap, list.capacity());
+
+            let mut list: LinkedList<String> = LinkedList::with_chunk_size(cap);
+            list.push_back(String::new());
+            assert_eq!(cap, list.capacity());
+            list.reserve(cap);
+            assert_eq!(2 * cap, list.capacity());
+            list.reserve_exact(3 * cap);
+            assert_eq!(3 * cap + 1, list.capacity());
         }
-        out.finish()
     }
-}
+    #[test]
+    fn cursor_delete_while() {
+        let mut list: LinkedList<u32> = vec![1, 2, 2, 2, 3, 2].into_iter().collect();
+        let capacity = list.capacity();
+
+        // a run in the middle
+        let (removed, cursor) = list.cursor_mut_at(1).unwrap().delete_while(|&v| v == 2);
+        assert_eq!(3, removed);
+        let cursor = cursor.unwrap();
+        assert_eq!(&3, cursor.get_ref());
+        cursor.assert_index_consistent();
+        assert_eq!(list, vec![1, 3, 2]);
+
+        // no matches
+        let (removed, cursor) = list.cursor_mut_at(1).unwrap().delete_while(|&v| v == 2);
+        assert_eq!(0, removed);
+        assert_eq!(1, cursor.unwrap().index());
+        assert_eq!(list, vec![1, 3, 2]);
+
+        // a run reaching the back
+        let (removed, cursor) = list.cursor_mut_at(1).unwrap().delete_while(|&v| v > 1);
+        assert_eq!(2, removed);
+        assert!(cursor.is_none());
+        assert_eq!(list, vec![1]);
+        assert_eq!(Some(&1), list.back());
 
-// serde impls
-#[cfg(feature = "serde")]
-extern crate serde;
-#[cfg(all(feature = "serde", test))]
-extern crate serde_json;
-#[cfg(feature = "serde")]
-use serde::{de::SeqAccess, de::Visitor, Deserialize, Deserializer, Serialize, Serializer};
+        // every element
+        let (removed, cursor) = list.cursor_mut_front().unwrap().delete_while(|_| true);
+        assert_eq!(1, removed);
+        assert!(cursor.is_none());
+        assert!(list.is_empty());
+        assert_eq!(capacity, list.capacity());
+    }
+    #[test]
+    fn splice() {
+        let replacements: Vec<Vec<u32>> =
+            vec![vec![], vec![10], vec![10, 11], vec![10, 11, 12, 13, 14]];
+        for start in 0..=6 {
+            for end in start..=6 {
+                for replacement in &replacements {
+                    let mut vec: Vec<u32> = (0..6).collect();
+                    let mut list: LinkedList<u32> = (0..6).collect();
+                    let capacity = list.capacity();
 
-#[cfg(feature = "serde")]
-impl<T: Serialize> Serialize for LinkedList<T> {
-    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
-        use serde::ser::SerializeSeq;
-        let mut seq = serializer.serialize_seq(Some(self.len()))?;
-        for e in self.iter() {
-            seq.serialize_element(e)?;
+                    let expected: Vec<u32> = vec.splice(start..end, replacement.clone()).collect();
+                    let removed: Vec<u32> = list.splice(start..end, replacement.clone()).collect();
+                    assert_eq!(expected, removed);
+                    assert_eq!(list, vec);
+                    assert!(list.iter().rev().eq(vec.iter().rev()));
+                    if replacement.len() <= end - start {
+                        // the replacement fits in the nodes of the removed elements
+                        assert_eq!(capacity, list.capacity());
+                    }
+                }
+            }
         }
-        seq.end()
+
+        let mut list: LinkedList<u32> = (0..6).collect();
+        assert!(list.splice(.., None).eq(0..6));
+        assert!(list.is_empty());
+        assert_eq!(0, list.splice(.., vec![1, 2]).len());
+        assert_eq!(list, vec![1, 2]);
+        assert!(list.splice(..=0, vec![0]).eq(Some(1)));
+        assert!(list.splice(1.., vec![3]).eq(Some(2)));
+        assert_eq!(list, vec![0, 3]);
     }
-}
-#[cfg(feature = "serde")]
-struct LinkedListVisitor<T> {
-    marker: PhantomData<T>,
-}
-#[cfg(feature = "serde")]
-impl<'de, T: Deserialize<'de>> Visitor<'de> for LinkedListVisitor<T> {
-    type Value = LinkedList<T>;
-    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
-        write!(formatter, "a sequence")
+    #[test]
+    #[should_panic]
+    fn splice_out_of_bounds() {
+        let mut list: LinkedList<u32> = (0..6).collect();
+        list.splice(2..7, None);
     }
-    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
-        let mut list = match seq.size_hint() {
-            Some(hint) => LinkedList::with_capacity(hint),
-            None => LinkedList::new(),
-        };
-        while let Some(next) = seq.next_element()? {
-            list.push_back(next);
+    #[test]
+    #[should_panic]
+    #[allow(clippy::reversed_empty_ranges)]
+    fn splice_reversed_range() {
+        let mut list: LinkedList<u32> = (0..6).collect();
+        list.splice(4..2, None);
+    }
+    #[test]
+    fn rotate_matches_vec_deque() {
+        use std::collections::VecDeque;
+        for len in 0..8 {
+            for n in 0..=len {
+                let mut deque: VecDeque<u32> = (0..len as u32).collect();
+                let mut list: LinkedList<u32> = (0..len as u32).collect();
+                deque.rotate_left(n);
+                list.rotate_left(n);
+                assert!(list.iter().eq(deque.iter()));
+                assert!(list.iter().rev().eq(deque.iter().rev()));
+
+                deque.rotate_right(n);
+                list.rotate_right(n);
+                assert!(list.iter().eq(deque.iter()));
+                assert!(list.iter().rev().eq(deque.iter().rev()));
+                assert_eq!(list, (0..len as u32).collect::<Vec<u32>>());
+            }
         }
-        Ok(list)
     }
-}
-#[cfg(feature = "serde")]
-impl<'de, T: Deserialize<'de>> Deserialize<'de> for LinkedList<T> {
-    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
-        deserializer.deserialize_seq(LinkedListVisitor {
-            marker: PhantomData,
-        })
+    #[test]
+    #[should_panic]
+    fn rotate_left_past_len() {
+        let mut list: LinkedList<u32> = (0..5).collect();
+        list.rotate_left(6);
     }
-}
+    #[test]
+    #[should_panic]
+    fn rotate_right_past_len() {
+        let mut list: LinkedList<u32> = (0..5).collect();
+        list.rotate_right(6);
+    }
+    #[test]
+    fn clone_keeps_chunk_size() {
+        let mut list: LinkedList<u32> = LinkedList::new();
+        list.set_chunk_size(7);
+        list.extend(0..10);
 
-#[cfg(all(feature = "serde", test))]
-mod serde_test {
-    use super::*;
-    use rand::prelude::*;
+        let mut clone = list.clone();
+        assert_eq!(7, clone.chunk_size());
+        assert_eq!(clone, list);
+        assert_eq!(10, clone.capacity());
+        clone.push_back(10);
+        assert_eq!(17, clone.capacity());
+
+        let mut other: LinkedList<u32> = (0..3).collect();
+        other.clone_from(&list);
+        assert_eq!(7, other.chunk_size());
+        assert_eq!(other, list);
+    }
     #[test]
-    fn serialize() {
+    fn front_back_mut() {
         let mut list: LinkedList<u32> = LinkedList::new();
-        list.set_chunk_size(328);
-        for _ in 0..1028 {
-            list.push_back(random());
+        assert_eq!((None, None), list.front_back_mut());
+
+        list.push_back(1);
+        if let (Some(front), None) = list.front_back_mut() {
+            *front = 10;
+        } else {
+            unreachable!();
         }
+        assert_eq!(list, vec![10]);
 
-        let json = serde_json::to_string(&list).unwrap();
-        println!("{}", &json);
-        let list2: LinkedList<u32> = serde_json::from_str(&json).unwrap();
+        list.push_back(2);
+        if let (Some(front), Some(back)) = list.front_back_mut() {
+            *front += 1;
+            *back += 1;
+        } else {
+            unreachable!();
+        }
+        assert_eq!(list, vec![11, 3]);
 
-        assert_eq!(list, list2);
+        list.extend(&[4, 5]);
+        if let (Some(front), Some(back)) = list.front_back_mut() {
+            *front += *back;
+            *back = 0;
+        } else {
+            unreachable!();
+        }
+        assert_eq!(list, vec![16, 3, 4, 0]);
     }
-}
+    #[test]
+    fn is_sorted() {
+        let empty: LinkedList<u32> = LinkedList::new();
+        assert!(empty.is_sorted());
+        let single: LinkedList<u32> = Some(3).into_iter().collect();
+        assert!(single.is_sorted());
+        let sorted: LinkedList<u32> = vec![1, 1, 2, 5, 8].into_iter().collect();
+        assert!(sorted.is_sorted());
+        let unsorted: LinkedList<u32> = vec![1, 2, 5, 4, 8].into_iter().collect();
+        assert!(!unsorted.is_sorted());
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use rand::prelude::*;
-    use std::fmt::Write;
+        // NaN can't be compared, so it is never in order
+        let nan: LinkedList<f64> = vec![1.0, f64::NAN, 2.0].into_iter().collect();
+        assert!(!nan.is_sorted());
+        let nan: LinkedList<f64> = Some(f64::NAN).into_iter().collect();
+        assert!(nan.is_sorted());
+
+        // the scan stops at the first pair out of order
+        let mut calls = 0;
+        assert!(!unsorted.is_sorted_by(|a, b| {
+            calls += 1;
+            a <= b
+        }));
+        assert_eq!(3, calls);
+        assert!(unsorted
+            .iter()
+            .rev()
+            .collect::<LinkedList<_>>()
+            .is_sorted_by(|a, b| a != b));
+    }
     #[test]
-    fn retain() {
-        let mut list: LinkedList<usize> = LinkedList::new();
-        for i in 0..16 {
-            list.push_back(i);
-        }
+    fn move_to_front_and_back() {
+        let mut list: LinkedList<u32> = (0..5).collect();
+        let capacity = list.capacity();
 
-        let mut rng = thread_rng();
+        assert!(list.move_to_front(&2));
+        assert_eq!(list, vec![2, 0, 1, 3, 4]);
+        assert!(list.iter().rev().eq([4, 3, 1, 0, 2].iter()));
+        assert!(list.move_to_front(&2));
+        assert_eq!(list, vec![2, 0, 1, 3, 4]);
+        assert!(list.move_to_front(&4));
+        assert_eq!(list, vec![4, 2, 0, 1, 3]);
+        assert_eq!(Some(&3), list.back());
 
-        let mut mask = [false; 16];
-        for val in mask.iter_mut() {
-            *val = rng.gen();
-        }
+        assert!(list.move_to_back(&2));
+        assert_eq!(list, vec![4, 0, 1, 3, 2]);
+        assert!(list.move_to_back(&4));
+        assert_eq!(list, vec![0, 1, 3, 2, 4]);
+        assert!(list.iter().rev().eq([4, 2, 3, 1, 0].iter()));
+        assert!(list.move_to_back(&4));
+        assert_eq!(Some(&0), list.front());
 
-        list.retain_map(|i| if mask[i] { Some(i + 1) } else { None });
+        assert!(!list.move_to_front(&7));
+        assert!(!list.move_to_back(&7));
+        assert_eq!(list, vec![0, 1, 3, 2, 4]);
+        assert_eq!(capacity, list.capacity());
 
-        let nums: Vec<usize> = (0..16).filter(|&i| mask[i]).map(|i| i + 1).collect();
+        let mut empty: LinkedList<u32> = LinkedList::new();
+        assert!(!empty.move_to_front(&0));
+        assert!(!empty.move_to_back(&0));
+        let mut single: LinkedList<u32> = Some(1).into_iter().collect();
+        assert!(single.move_to_front(&1));
+        assert!(single.move_to_back(&1));
+        assert_eq!(single, vec![1]);
+    }
+    #[test]
+    fn retain_panic_keeps_capacity() {
+        let retain = |list: &mut LinkedList<String>, panic_at: &str| {
+            list.retain(|s| {
+                if s == panic_at {
+                    panic!("retain");
+                }
+                s != "1"
+            })
+        };
+        let retain_mut = |list: &mut LinkedList<String>, panic_at: &str| {
+            list.retain_mut(|s| {
+                if s == panic_at {
+                    panic!("retain_mut");
+                }
+                s.push('!');
+                s != "2!"
+            })
+        };
+        let retain_map = |list: &mut LinkedList<String>, panic_at: &str| {
+            list.retain_map(|s| {
+                if s == panic_at {
+                    panic!("retain_map");
+                }
+                if s == "3" {
+                    None
+                } else {
+                    Some(s + "!")
+                }
+            })
+        };
+        type Retain<'a> = &'a dyn Fn(&mut LinkedList<String>, &str);
+        let retains: [Retain; 3] = [&retain, &retain_mut, &retain_map];
+        for retain in &retains {
+            for panic_at in 0..6 {
+                let mut list: LinkedList<String> = LinkedList::with_chunk_size(4);
+                list.extend((0..6).map(|i| i.to_string()));
+                let capacity = list.capacity();
+                let allocations = list.number_of_allocations();
 
-        println!("{:?}", mask);
-        for (a, b) in list.into_iter().zip(nums.into_iter()) {
-            assert_eq!(a, b);
+                let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+                    retain(&mut list, &panic_at.to_string());
+                }));
+                assert!(result.is_err());
+                assert!(list.is_empty());
+                assert_eq!(capacity, list.capacity());
+
+                list.extend((0..capacity).map(|i| i.to_string()));
+                assert_eq!(capacity, list.capacity());
+                assert_eq!(allocations, list.number_of_allocations());
+            }
+        }
+    }
+    #[test]
+    fn cursor_len_and_remaining() {
+        let list: LinkedList<u32> = (0..5).collect();
+        for &(index, forward) in &[(0, 4), (2, 2), (4, 0)] {
+            let cursor = list.cursor_ref_at(index).unwrap();
+            assert_eq!(5, cursor.len());
+            assert_eq!(forward, cursor.remaining_forward());
+            assert_eq!(index, cursor.remaining_backward());
+            let next = cursor.next().or(cursor.prev()).unwrap();
+            assert_eq!(5, next.len());
+            assert_eq!(4, next.remaining_forward() + next.remaining_backward());
         }
+        for &(index, forward) in &[(0, 4), (2, 2), (4, 0)] {
+            let mut list = list.clone();
+            let mut cursor = list.cursor_mut_at(index).unwrap();
+            assert_eq!(5, cursor.len());
+            assert_eq!(forward, cursor.remaining_forward());
+            assert_eq!(index, cursor.remaining_backward());
+            assert_eq!(5, cursor.as_cursor_ref().len());
+            cursor.insert_prev(10);
+            cursor.insert_next(10);
+            assert_eq!(7, cursor.len());
+            assert_eq!(forward + 1, cursor.remaining_forward());
+            assert_eq!(index + 1, cursor.remaining_backward());
+            assert_eq!(7, cursor.as_cursor_ref().len());
+            let cursor = cursor.remove_go_next().1.unwrap();
+            assert_eq!(6, cursor.len());
+            assert_eq!(forward, cursor.remaining_forward());
+        }
+        assert_eq!(list, vec![0, 1, 2, 3, 4]);
     }
+    #[test]
+    fn reserve_arithmetic() {
+        let mut list: LinkedList<u32> = LinkedList::with_capacity(5);
+        list.push_back(3);
+        list.reserve(84);
+        assert_eq!(85, list.capacity());
+        assert_eq!(2, list.number_of_allocations());
+        assert_eq!(80, list.allocations[1].1);
+
+        // enough free capacity spread over several chunks doesn't allocate
+        let mut list: LinkedList<u32> = LinkedList::with_chunk_size(4);
+        for _ in 0..3 {
+            list.reserve_exact(list.capacity() + 4 - list.len());
+            list.push_back(0);
+        }
+        let capacity = list.capacity();
+        let allocations = list.number_of_allocations();
+        list.reserve(capacity - list.len());
+        assert_eq!(capacity, list.capacity());
+        assert_eq!(allocations, list.number_of_allocations());
 
+        // small reservations are rounded up to the chunk size
+        list.reserve(capacity - list.len() + 1);
+        assert_eq!(capacity + 4, list.capacity());
+        // and large ones are exact
+        list.reserve(list.capacity() - list.len() + 9);
+        assert_eq!(capacity + 13, list.capacity());
+    }
     #[test]
-    fn iter_collect_compare() {
-        let mut list = LinkedList::new();
-        for i in 0..64usize {
-            list.push_back(i);
+    fn split_first_and_last_mut() {
+        let mut list: LinkedList<u32> = (0..5).collect();
+        {
+            let (first, mut rest) = list.split_first_mut().unwrap();
+            assert_eq!(4, rest.len());
+            let second = rest.next().unwrap();
+            let last = rest.next_back().unwrap();
+            *first += 10;
+            *second += 10;
+            *last += 10;
+            for value in rest {
+                *value += *first;
+            }
         }
-        let list2: LinkedList<u32> = list.iter().map(|&i| i as u32).collect();
-        let vec: Vec<u32> = list.into_iter().map(|i| i as u32).collect();
+        assert_eq!(list, vec![10, 11, 12, 13, 14]);
+        {
+            let (last, rest) = list.split_last_mut().unwrap();
+            assert_eq!(4, rest.len());
+            for value in rest.rev() {
+                *value -= 10;
+                *last += *value;
+            }
+        }
+        assert_eq!(list, vec![0, 1, 2, 3, 20]);
 
-        assert_eq!(list2, vec);
+        let mut single: LinkedList<u32> = Some(1).into_iter().collect();
+        {
+            let (first, mut rest) = single.split_first_mut().unwrap();
+            *first = 2;
+            assert_eq!(None, rest.next());
+            assert_eq!(None, rest.next_back());
+        }
+        {
+            let (last, mut rest) = single.split_last_mut().unwrap();
+            *last += 1;
+            assert_eq!(None, rest.next());
+            assert_eq!(None, rest.next_back());
+        }
+        assert_eq!(single, vec![3]);
+
+        let mut empty: LinkedList<u32> = LinkedList::new();
+        assert!(empty.split_first_mut().is_none());
+        assert!(empty.split_last_mut().is_none());
     }
     #[test]
-    fn debug_print_list() {
-        let mut output = String::new();
-        let mut list = LinkedList::new();
-        list.push_back(1);
-        list.push_back(2);
-        list.push_back(3);
-        list.push_back(4);
-        write!(output, "{:?}", list).unwrap();
-        assert_eq!(output, "[1, 2, 3, 4]");
+    fn append_elements_only() {
+        let mut a: LinkedList<u32> = (0..3).collect();
+        let mut b: LinkedList<u32> = LinkedList::with_capacity_and_chunk_size(10, 4);
+        b.extend(3..8);
+        let allocations = b.number_of_allocations();
+
+        a.append_elements_only(&mut b);
+        assert_eq!(a, (0..8).collect::<Vec<u32>>());
+        assert!(a.iter().rev().eq((0..8).rev().collect::<Vec<u32>>().iter()));
+        assert!(b.is_empty());
+        assert_eq!(10, b.capacity());
+        assert_eq!(4, b.chunk_size());
+        assert_eq!(allocations, b.number_of_allocations());
+
+        // other can be refilled without allocating
+        b.extend(0..10);
+        assert_eq!(10, b.capacity());
+        assert_eq!(allocations, b.number_of_allocations());
+
+        // appending into an empty list, and appending an empty list
+        let mut c: LinkedList<u32> = LinkedList::new();
+        c.append_elements_only(&mut b);
+        assert_eq!(c, (0..10).collect::<Vec<u32>>());
+        let capacity = c.capacity();
+        c.append_elements_only(&mut b);
+        assert_eq!(10, c.len());
+        assert_eq!(capacity, c.capacity());
     }
     #[test]
-    fn debug_print_iter() {
-        let mut output = String::new();
-        let mut list = LinkedList::new();
-        list.push_back(1);
-        list.push_back(2);
-        list.push_back(3);
-        list.push_back(4);
+    fn size_hint_is_exact_after_partial_consumption() {
+        let mut list: LinkedList<u32> = (0..10).collect();
 
         let mut iter = list.iter();
+        for remaining in (0..10).rev() {
+            if remaining % 2 == 0 {
+                iter.next();
+            } else {
+                iter.next_back();
+            }
+            assert_eq!((remaining, Some(remaining)), iter.size_hint());
+            assert_eq!(remaining, iter.len());
+        }
 
-        write!(output, "{:?}", iter).unwrap();
-        assert_eq!(output, "LinkedList::Iter[1, 2, 3, 4]");
-        output.clear();
+        let mut iter = list.iter();
+        iter.nth(2);
+        iter.nth_back(1);
+        assert_eq!((5, Some(5)), iter.size_hint());
+        let vec: Vec<&u32> = iter.collect();
+        assert_eq!(vec, [&3, &4, &5, &6, &7]);
+        assert_eq!(5, vec.capacity());
 
-        let _ = iter.next();
-        let _ = iter.next_back();
+        let mut iter = list.iter_mut();
+        iter.next();
+        iter.next_back();
+        iter.next_back();
+        assert_eq!((7, Some(7)), iter.size_hint());
+        let vec: Vec<&mut u32> = iter.collect();
+        assert_eq!(7, vec.len());
+        assert_eq!(7, vec.capacity());
 
-        write!(output, "{:?}", iter).unwrap();
-        assert_eq!(output, "LinkedList::Iter[2, 3]");
-        output.clear();
+        let mut iter = list.into_iter();
+        iter.next();
+        iter.next();
+        iter.next_back();
+        assert_eq!((7, Some(7)), iter.size_hint());
+        let vec: Vec<u32> = iter.collect();
+        assert_eq!(vec, [2, 3, 4, 5, 6, 7, 8]);
+        assert_eq!(7, vec.capacity());
     }
     #[test]
-    fn debug_print_iter_mut() {
-        let mut output = String::new();
-        let mut list = LinkedList::new();
-        list.push_back(1);
-        list.push_back(2);
-        list.push_back(3);
-        list.push_back(4);
+    fn interleave() {
+        for self_len in 0..5u32 {
+            for other_len in 0..5u32 {
+                let mut a: LinkedList<u32> = (0..self_len).map(|i| 2 * i).collect();
+                let mut b: LinkedList<u32> = LinkedList::with_capacity(other_len as usize + 1);
+                b.extend((0..other_len).map(|i| 2 * i + 1));
+                let capacity = a.capacity() + b.capacity();
 
-        let mut iter = list.iter_mut();
+                let mut expected = Vec::new();
+                for i in 0..cmp::max(self_len, other_len) {
+                    if i < self_len {
+                        expected.push(2 * i);
+                    }
+                    if i < other_len {
+                        expected.push(2 * i + 1);
+                    }
+                }
 
-        write!(output, "{:?}", iter).unwrap();
-        assert_eq!(output, "LinkedList::IterMut[1, 2, 3, 4]");
-        output.clear();
+                a.interleave(&mut b);
+                assert_eq!(a, expected);
+                assert!(a.iter().rev().eq(expected.iter().rev()));
+                assert_eq!(expected.first(), a.front());
+                assert_eq!(expected.last(), a.back());
+                assert!(b.is_empty());
+                assert_eq!(0, b.capacity());
+                assert_eq!(capacity, a.capacity());
 
-        let _ = iter.next();
-        let _ = iter.next_back();
+                // the unused nodes moved as well
+                while a.len() < capacity {
+                    a.push_back(0);
+                }
+                assert_eq!(capacity, a.capacity());
+            }
+        }
+    }
+    #[test]
+    fn pop_front_into() {
+        let mut list: LinkedList<u32> = LinkedList::with_capacity(10);
+        list.extend(0..10);
+        let mut buf = Vec::with_capacity(4);
+        let buf_ptr = buf.as_ptr();
 
-        write!(output, "{:?}", iter).unwrap();
-        assert_eq!(output, "LinkedList::IterMut[2, 3]");
-        output.clear();
+        assert_eq!(4, list.pop_front_into(&mut buf, 4));
+        assert_eq!(buf, [0, 1, 2, 3]);
+        // the buffer was big enough, so it wasn't reallocated
+        assert_eq!(buf_ptr, buf.as_ptr());
+        assert_eq!(list, vec![4, 5, 6, 7, 8, 9]);
+
+        buf.clear();
+        assert_eq!(0, list.pop_front_into(&mut buf, 0));
+        assert!(buf.is_empty());
+
+        assert_eq!(6, list.pop_front_into(&mut buf, 100));
+        assert_eq!(buf, [4, 5, 6, 7, 8, 9]);
+        assert!(list.is_empty());
+        assert_eq!(0, list.pop_front_into(&mut buf, 1));
+
+        // the popped nodes are reused without allocating
+        assert_eq!(10, list.capacity());
+        list.extend(0..10);
+        assert_eq!(10, list.capacity());
+        assert_eq!(1, list.number_of_allocations());
+        assert_eq!(list, (0..10).collect::<Vec<_>>());
     }
     #[test]
-    fn debug_print_into_iter() {
-        let mut output = String::new();
-        let mut list = LinkedList::new();
-        list.push_back(1);
-        list.push_back(2);
-        list.push_back(3);
-        list.push_back(4);
+    fn ghost_cursor_insert_into_empty() {
+        let mut list: LinkedList<u32> = LinkedList::new();
+        {
+            let mut cursor = list.cursor_mut_front_or_empty();
+            assert!(cursor.is_ghost());
+            assert_eq!(None, cursor.index());
+            assert_eq!(None, cursor.get());
 
-        let mut iter = list.into_iter();
+            // moving around an empty list stays at the ghost
+            cursor.go_next();
+            assert!(cursor.is_ghost());
+            cursor.go_prev();
+            assert!(cursor.is_ghost());
 
-        write!(output, "{:?}", iter).unwrap();
-        assert_eq!(output, "LinkedList::IntoIter[1, 2, 3, 4]");
-        output.clear();
+            cursor.insert_prev(2);
+            assert!(cursor.is_ghost());
+            assert_eq!(1, cursor.len());
 
-        let _ = iter.next();
-        let _ = iter.next_back();
+            cursor.go_next();
+            assert_eq!(Some(0), cursor.index());
+            assert_eq!(Some(&mut 2), cursor.get());
+            cursor.insert_prev(1);
+            cursor.insert_next(3);
+            assert_eq!(Some(1), cursor.index());
 
-        write!(output, "{:?}", iter).unwrap();
-        assert_eq!(output, "LinkedList::IntoIter[2, 3]");
-        output.clear();
+            cursor.go_next();
+            cursor.go_next();
+            assert!(cursor.is_ghost());
+            cursor.insert_next(0);
+            cursor.insert_prev(4);
+            cursor.go_prev();
+            assert_eq!(Some(4), cursor.index());
+            assert_eq!(Some(&4), cursor.get_ref());
+        }
+        assert_eq!(list, vec![0, 1, 2, 3, 4]);
+        assert!(list.iter().rev().eq([4, 3, 2, 1, 0].iter()));
+
+        let mut list: LinkedList<u32> = LinkedList::new();
+        {
+            let mut cursor = list.cursor_mut_back_or_empty();
+            assert!(cursor.is_ghost());
+            cursor.insert_next(5);
+            cursor.go_prev();
+            let mut cursor = cursor.into_cursor_mut().unwrap();
+            assert_eq!(0, cursor.index());
+            assert_eq!(&mut 5, cursor.get());
+        }
+        assert_eq!(list, vec![5]);
+        assert!(list.cursor_mut_front_or_empty().into_cursor_mut().is_some());
+        list.clear();
+        assert!(list.cursor_mut_front_or_empty().into_cursor_mut().is_none());
     }
     #[test]
-    fn iter_mut_several_mut_ref() {
-        let mut list = LinkedList::new();
-        list.push_back(1);
-        list.push_back(2);
-        list.push_back(3);
-        list.push_back(4);
+    fn ghost_cursor_build_and_splice() {
+        // build a list through a cursor, like std's cursor_front_mut on an empty list
+        let mut list: LinkedList<u32> = LinkedList::new();
+        {
+            let mut cursor = list.cursor_mut();
+            assert!(cursor.is_ghost());
+            cursor.insert_next(1);
+            cursor.go_next();
+            assert_eq!(Some(0), cursor.index());
+            cursor.insert_next(3);
+            cursor.go_next();
+            cursor.insert_prev(2);
+            assert_eq!(Some(2), cursor.index());
+            cursor.go_next();
+            assert!(cursor.is_ghost());
+            cursor.insert_prev(4);
+        }
+        assert_eq!(list, vec![1, 2, 3, 4]);
 
+        // splice into an empty list at the ghost
+        let mut empty: LinkedList<u32> = LinkedList::new();
+        let capacity = list.capacity();
         {
-            let mut iter_mut = list.iter_mut();
-            let ref1 = iter_mut.next().unwrap();
-            let ref2 = iter_mut.next().unwrap();
-            drop(iter_mut);
-            *ref1 = 6;
-            *ref2 = 7;
+            let mut cursor = empty.cursor_mut();
+            cursor.splice_after_list(&mut list);
+            assert!(cursor.is_ghost());
+            cursor.go_next();
+            assert_eq!(Some(&mut 1), cursor.get());
+            assert_eq!(4, cursor.len());
         }
+        assert_eq!(empty, vec![1, 2, 3, 4]);
+        assert_eq!(capacity, empty.capacity());
+        assert_eq!(0, list.capacity());
 
-        assert_eq!(list, vec![6, 7, 3, 4]);
+        // splicing at the ghost goes to the front or the back
+        let mut front: LinkedList<u32> = (10..12).collect();
+        let mut back: LinkedList<u32> = (20..22).collect();
+        let mut middle: LinkedList<u32> = (30..32).collect();
+        {
+            let mut cursor = empty.cursor_mut();
+            cursor.splice_after_list(&mut front);
+            cursor.splice_before_list(&mut back);
+            assert!(cursor.is_ghost());
+            cursor.go_next();
+            cursor.go_next();
+            cursor.go_next();
+            assert_eq!(Some(&1), cursor.get_ref());
+            cursor.splice_before_list(&mut middle);
+            assert_eq!(Some(4), cursor.index());
+            assert_eq!(Some(1), cursor.remove_current());
+            assert_eq!(Some(&2), cursor.get_ref());
+            assert_eq!(Some(4), cursor.index());
+        }
+        assert_eq!(empty, vec![10, 11, 30, 31, 2, 3, 4, 20, 21]);
+        assert!(empty
+            .iter()
+            .rev()
+            .eq([21, 20, 4, 3, 2, 31, 30, 11, 10].iter()));
+
+        // remove everything through the cursor
+        {
+            let mut cursor = empty.cursor_mut();
+            cursor.go_next();
+            while cursor.remove_current().is_some() {}
+            assert!(cursor.is_ghost());
+            assert_eq!(0, cursor.len());
+        }
+        assert!(empty.is_empty());
+        assert_eq!(capacity + 6, empty.capacity());
+    }
+    #[test]
+    fn dedup_collect() {
+        let mut list: LinkedList<u32> = LinkedList::new();
+        list.extend(&[1, 1, 2, 3, 3, 3]);
+        let capacity = list.capacity();
+
+        let removed = list.dedup_collect();
+        assert_eq!(list, vec![1, 2, 3]);
+        assert!(list.iter().rev().eq([3, 2, 1].iter()));
+        assert_eq!(removed, vec![1, 3, 3]);
+        assert_eq!(removed.chunk_size(), list.chunk_size());
+        assert_eq!(capacity, list.capacity());
+
+        // nothing left to remove
+        assert!(list.dedup_collect().is_empty());
+        assert_eq!(list, vec![1, 2, 3]);
+
+        let mut list: LinkedList<u32> = LinkedList::new();
+        assert!(list.dedup_collect().is_empty());
+        list.push_back(1);
+        assert!(list.dedup_collect().is_empty());
+        assert_eq!(list, vec![1]);
+
+        // the removed nodes are reused
+        let mut list: LinkedList<u32> = LinkedList::with_capacity(6);
+        list.extend(&[4, 4, 4, 4, 4, 4]);
+        assert_eq!(list.dedup_collect(), vec![4, 4, 4, 4, 4]);
+        list.extend(0..5);
+        assert_eq!(6, list.capacity());
+        assert_eq!(list, vec![4, 0, 1, 2, 3, 4]);
+    }
+    #[test]
+    fn to_vec_allocates_once() {
+        let list: LinkedList<String> = (0..10).map(|i| i.to_string()).collect();
+
+        let vec = list.to_vec();
+        assert_eq!(vec, list.iter().cloned().collect::<Vec<String>>());
+        assert_eq!(10, vec.capacity());
+
+        let refs = list.to_vec_refs();
+        assert_eq!(refs, list.iter().collect::<Vec<&String>>());
+        assert_eq!(10, refs.capacity());
+
+        let empty: LinkedList<String> = LinkedList::new();
+        assert_eq!(0, empty.to_vec().capacity());
+        assert_eq!(0, empty.to_vec_refs().capacity());
     }
 }
@@ -0,0 +1,182 @@
+//! Serialization of a `LinkedList` together with its chunk size.
+//!
+//! By default a `LinkedList` is serialized as a plain sequence, so the chunk size is lost
+//! when deserializing it again. This module serializes the list as a struct on the form
+//! `{ "chunk_size": N, "items": [...] }` instead, and is intended to be used with the
+//! `#[serde(with = "linked_list::serde_chunk_size")]` attribute on fields of type
+//! `LinkedList<T>`.
+//!
+//! The functions can also be called directly:
+//!
+//! ```
+//! extern crate linked_list;
+//! extern crate serde_json;
+//!
+//! use linked_list::{serde_chunk_size, LinkedList};
+//!
+//! # fn main() {
+//! let mut list: LinkedList<u32> = LinkedList::with_chunk_size(8);
+//! list.extend(&[1, 2, 3]);
+//!
+//! let mut json = Vec::new();
+//! serde_chunk_size::serialize(&list, &mut serde_json::Serializer::new(&mut json)).unwrap();
+//! assert_eq!(&json[..], &br#"{"chunk_size":8,"items":[1,2,3]}"#[..]);
+//!
+//! let mut deserializer = serde_json::Deserializer::from_slice(&json);
+//! let list2: LinkedList<u32> = serde_chunk_size::deserialize(&mut deserializer).unwrap();
+//! assert_eq!(list, list2);
+//! assert_eq!(8, list2.chunk_size());
+//! # }
+//! ```
+use super::*;
+
+use serde::de::{self, DeserializeSeed, MapAccess};
+use serde::ser::SerializeStruct;
+
+const FIELDS: &[&str] = &["chunk_size", "items"];
+
+/// Serializes the list as a struct containing the chunk size and the items.
+pub fn serialize<T: Serialize, A: Allocator, S: Serializer>(
+    list: &LinkedList<T, A>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    let mut state = serializer.serialize_struct("LinkedList", 2)?;
+    state.serialize_field("chunk_size", &list.chunk_size())?;
+    state.serialize_field("items", list)?;
+    state.end()
+}
+
+/// Deserializes a list serialized with [`serialize`], creating it with the stored chunk
+/// size.
+///
+/// [`serialize`]: fn.serialize.html
+pub fn deserialize<'de, T: Deserialize<'de>, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<LinkedList<T>, D::Error> {
+    deserializer.deserialize_struct(
+        "LinkedList",
+        FIELDS,
+        ChunkSizeVisitor {
+            marker: PhantomData,
+        },
+    )
+}
+
+enum Field {
+    ChunkSize,
+    Items,
+}
+impl<'de> Deserialize<'de> for Field {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct FieldVisitor;
+        impl<'de> Visitor<'de> for FieldVisitor {
+            type Value = Field;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                write!(formatter, "`chunk_size` or `items`")
+            }
+            fn visit_str<E: de::Error>(self, value: &str) -> Result<Field, E> {
+                match value {
+                    "chunk_size" => Ok(Field::ChunkSize),
+                    "items" => Ok(Field::Items),
+                    _ => Err(de::Error::unknown_field(value, FIELDS)),
+                }
+            }
+        }
+        deserializer.deserialize_identifier(FieldVisitor)
+    }
+}
+
+/// Deserializes the items directly into a list with the given chunk size, if known.
+struct ItemsSeed<T> {
+    chunk_size: Option<usize>,
+    marker: PhantomData<T>,
+}
+impl<'de, T: Deserialize<'de>> DeserializeSeed<'de> for ItemsSeed<T> {
+    type Value = LinkedList<T>;
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        deserializer.deserialize_seq(self)
+    }
+}
+impl<'de, T: Deserialize<'de>> Visitor<'de> for ItemsSeed<T> {
+    type Value = LinkedList<T>;
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "a sequence")
+    }
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut list = match self.chunk_size {
+            Some(chunk_size) => {
+                LinkedList::with_capacity_and_chunk_size(seq.size_hint().unwrap_or(0), chunk_size)
+            }
+            None => LinkedList::with_capacity(seq.size_hint().unwrap_or(0)),
+        };
+        while let Some(next) = seq.next_element()? {
+            list.push_back(next);
+        }
+        Ok(list)
+    }
+}
+
+/// The chunk size must be positive, as `set_chunk_size` would panic otherwise.
+fn check_chunk_size<E: de::Error>(chunk_size: usize) -> Result<usize, E> {
+    if chunk_size == 0 {
+        Err(de::Error::invalid_value(
+            de::Unexpected::Unsigned(0),
+            &"a positive chunk size",
+        ))
+    } else {
+        Ok(chunk_size)
+    }
+}
+
+struct ChunkSizeVisitor<T> {
+    marker: PhantomData<T>,
+}
+impl<'de, T: Deserialize<'de>> Visitor<'de> for ChunkSizeVisitor<T> {
+    type Value = LinkedList<T>;
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            formatter,
+            "a struct with the fields `chunk_size` and `items`"
+        )
+    }
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let chunk_size: usize = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+        let chunk_size = check_chunk_size(chunk_size)?;
+        let seed = ItemsSeed {
+            chunk_size: Some(chunk_size),
+            marker: PhantomData,
+        };
+        seq.next_element_seed(seed)?
+            .ok_or_else(|| de::Error::invalid_length(1, &self))
+    }
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut chunk_size: Option<usize> = None;
+        let mut items: Option<LinkedList<T>> = None;
+        while let Some(key) = map.next_key()? {
+            match key {
+                Field::ChunkSize => {
+                    if chunk_size.is_some() {
+                        return Err(de::Error::duplicate_field("chunk_size"));
+                    }
+                    chunk_size = Some(check_chunk_size(map.next_value()?)?);
+                }
+                Field::Items => {
+                    if items.is_some() {
+                        return Err(de::Error::duplicate_field("items"));
+                    }
+                    items = Some(map.next_value_seed(ItemsSeed {
+                        chunk_size,
+                        marker: PhantomData,
+                    })?);
+                }
+            }
+        }
+        let chunk_size = chunk_size.ok_or_else(|| de::Error::missing_field("chunk_size"))?;
+        let mut items = items.ok_or_else(|| de::Error::missing_field("items"))?;
+        // if the items came before the chunk size, they used the default chunk size
+        items.set_chunk_size(chunk_size);
+        Ok(items)
+    }
+}
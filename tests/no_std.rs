@@ -0,0 +1,28 @@
+//! Uses the list from a `no_std` crate, to check that the public API only needs `core`
+//! and `alloc`. The test harness still links `std`, so this doesn't check that the
+//! library builds without it. That is done by building for a target without `std`, such
+//! as `cargo build --no-default-features --target thumbv7em-none-eabihf`.
+#![no_std]
+
+extern crate alloc;
+extern crate linked_list;
+
+use alloc::vec::Vec;
+use linked_list::LinkedList;
+
+#[test]
+fn no_std_list() {
+    let mut list: LinkedList<u32> = LinkedList::with_chunk_size(4);
+    list.extend(0..10);
+    list.push_front(10);
+    list.retain(|&i| i % 2 == 0);
+
+    if let Some(mut cursor) = list.cursor_mut_front() {
+        cursor.insert_next(11);
+    }
+
+    let vec: Vec<u32> = list.iter().cloned().collect();
+    assert_eq!(vec, [10, 11, 0, 2, 4, 6, 8]);
+    assert_eq!(list.pop_back(), Some(8));
+    assert_eq!(list.len(), 6);
+}